@@ -0,0 +1,44 @@
+use scrypto::prelude::*;
+
+use crate::validation;
+
+#[derive(ScryptoSbor, Clone, Debug)]
+pub enum AdditionalDataChange {
+    Set { key: String, value: String },
+    Remove { key: String },
+    SetVec { key: String, values: Vec<String> },
+    AppendVec { key: String, values: Vec<String> },
+    RemoveVec { key: String },
+}
+
+// limits are checked on the result, so a change set may free space before filling it again
+pub(crate) fn apply(
+    additional_data: &mut HashMap<String, String>,
+    additional_data_vec: &mut HashMap<String, Vec<String>>,
+    changes: Vec<AdditionalDataChange>,
+) {
+    assert!(!changes.is_empty(), "No additional data changes provided");
+
+    for change in changes {
+        match change {
+            AdditionalDataChange::Set { key, value } => {
+                additional_data.insert(key, value);
+            }
+            AdditionalDataChange::Remove { key } => {
+                assert!(additional_data.remove(&key).is_some(), "Additional data has no key {}", key);
+            }
+            AdditionalDataChange::SetVec { key, values } => {
+                additional_data_vec.insert(key, values);
+            }
+            AdditionalDataChange::AppendVec { key, values } => {
+                additional_data_vec.entry(key).or_default().extend(values);
+            }
+            AdditionalDataChange::RemoveVec { key } => {
+                assert!(additional_data_vec.remove(&key).is_some(), "Additional data vec has no key {}", key);
+            }
+        }
+    }
+
+    validation::check_additional_data(additional_data);
+    validation::check_additional_data_vec(additional_data_vec);
+}
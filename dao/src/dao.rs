@@ -1,11 +1,74 @@
 use scrypto::prelude::*;
 
 use crate::{proposal, utils};
+use crate::views::DaoView;
+
+#[derive(ScryptoSbor, PartialEq, Clone, Copy, Debug)]
+pub enum DaoType {
+    NftCollection,
+    FungibleToken,
+    Membership,
+    Multi,
+}
+
+impl DaoType {
+    pub(crate) fn check_governance_resource(&self, governance_resource: ResourceAddress) {
+        match self {
+            DaoType::NftCollection | DaoType::Membership => assert!(!governance_resource.is_fungible(), "Governance resource of a {:?} DAO must be non-fungible", self),
+            DaoType::FungibleToken => assert!(governance_resource.is_fungible(), "Governance resource of a {:?} DAO must be fungible", self),
+            DaoType::Multi => {}
+        }
+    }
+
+    // proposals are voted with NFTs of the governance resource
+    pub(crate) fn check_nft_votes(&self, governance_resource: ResourceAddress) {
+        let supports_nft_votes = match self {
+            DaoType::NftCollection | DaoType::Membership => true,
+            DaoType::FungibleToken => false,
+            DaoType::Multi => !governance_resource.is_fungible(),
+        };
+        assert!(supports_nft_votes, "NFT voting does not fit a {:?} DAO with governance resource {:?}", self, governance_resource);
+    }
+}
+
+#[derive(ScryptoSbor, Clone, Debug, Default)]
+pub struct DaoPatch {
+    pub name: Option<String>,
+    pub info_url: Option<String>,
+    pub key_image_url: Option<String>,
+    pub dao_type: Option<DaoType>,
+    pub governance_resource: Option<ResourceAddress>,
+    pub about: Option<String>,
+    pub general: Option<String>,
+    pub rules: Option<Vec<String>>,
+    pub additional_data: Option<HashMap<String, String>>,
+}
+
+impl DaoPatch {
+    pub(crate) fn is_empty(&self) -> bool {
+        self.name.is_none()
+            && self.info_url.is_none()
+            && self.key_image_url.is_none()
+            && self.dao_type.is_none()
+            && self.governance_resource.is_none()
+            && self.about.is_none()
+            && self.general.is_none()
+            && self.rules.is_none()
+            && self.additional_data.is_none()
+    }
+}
+
+#[derive(ScryptoSbor, PartialEq, Clone, Copy, Debug)]
+pub enum DaoStatus {
+    Active,
+    Archived,
+}
 
 #[derive(ScryptoSbor, NonFungibleData)]
 pub(crate) struct Dao {
     dao_id: String,
     component_type: String,
+    #[mutable]
     version: u32,
     #[mutable]
     name: String,
@@ -16,7 +79,7 @@ pub(crate) struct Dao {
     #[mutable]
     key_image_url: Url,
     #[mutable]
-    dao_type: String,
+    dao_type: DaoType,
     #[mutable]
     governance_resource: ResourceAddress,
     #[mutable]
@@ -32,6 +95,15 @@ pub(crate) struct Dao {
     #[mutable]
     additional_data_vec: HashMap<String, Vec<String>>,
     proposals: ResourceManager,
+    #[mutable]
+    status: DaoStatus,
+    // a freeze keeps the status, so unfreezing restores it
+    #[mutable]
+    frozen: bool,
+    #[mutable]
+    revision: u64,
+    #[mutable]
+    updated_epoch: Option<Epoch>,
 }
 
 impl Dao {
@@ -43,8 +115,8 @@ impl Dao {
         self.proposals
     }
 
-    pub(crate) fn dao_type(&self) -> String {
-        self.dao_type.clone()
+    pub(crate) fn dao_type(&self) -> DaoType {
+        self.dao_type
     }
 
     pub(crate) fn governance_resource(&self) -> ResourceAddress {
@@ -54,16 +126,75 @@ impl Dao {
     pub(crate) fn key_image_url(&self) -> Url {
         self.key_image_url.clone()
     }
+
+    pub(crate) fn additional_data(&self) -> HashMap<String, String> {
+        self.additional_data.clone()
+    }
+
+    pub(crate) fn additional_data_vec(&self) -> HashMap<String, Vec<String>> {
+        self.additional_data_vec.clone()
+    }
+
+    pub(crate) fn version(&self) -> u32 {
+        self.version
+    }
+
+    pub(crate) fn status(&self) -> DaoStatus {
+        self.status
+    }
+
+    pub(crate) fn is_frozen(&self) -> bool {
+        self.frozen
+    }
+
+    // archived or frozen DAOs accept no new proposals, options or votes
+    pub(crate) fn check_active(&self) {
+        self.check_not_frozen();
+        assert_eq!(self.status, DaoStatus::Active, "DAO {} is {:?}", self.dao_id, self.status);
+    }
+
+    // frozen DAOs can not be changed by their admins
+    pub(crate) fn check_not_frozen(&self) {
+        assert!(!self.frozen, "DAO {} is frozen", self.dao_id);
+    }
+
+    pub(crate) fn view(&self) -> DaoView {
+        DaoView {
+            dao_id: self.dao_id.clone(),
+            version: self.version,
+            revision: self.revision,
+            updated_epoch: self.updated_epoch,
+            name: self.name.clone(),
+            description: self.description.clone(),
+            info_url: self.info_url.clone(),
+            key_image_url: self.key_image_url.clone(),
+            dao_type: self.dao_type,
+            governance_resource: self.governance_resource,
+            about: self.about.clone(),
+            general: self.general.clone(),
+            created: self.created.clone(),
+            created_epoch: self.created_epoch,
+            rules: self.rules.clone(),
+            additional_data: self.additional_data.clone(),
+            additional_data_vec: self.additional_data_vec.clone(),
+            proposals: self.proposals.address(),
+            status: self.status,
+            frozen: self.frozen,
+        }
+    }
 }
 
-const VERSION: u32 = 1;
+// version 2 keeps later added fields in the component side DAO extension
+pub(crate) const VERSION: u32 = 2;
 
 
 pub(crate) fn create_resource_manager(
     owner_badge_address: ResourceAddress,
     owner_badge_access_rule: &AccessRule,
     component_address: ComponentAddress,
+    soulbound: bool,
 ) -> ResourceManager {
+    let withdrawer_rule = if soulbound { rule!(deny_all) } else { rule!(allow_all) };
     let dao_resource_manager =
         ResourceBuilder::new_string_non_fungible::<Dao>(OwnerRole::Fixed(owner_badge_access_rule.clone()))
             .metadata(metadata!(
@@ -100,7 +231,7 @@ pub(crate) fn create_resource_manager(
                     burner_updater => OWNER;
                 })
             .withdraw_roles(withdraw_roles! {
-                    withdrawer => rule!(allow_all);
+                    withdrawer => withdrawer_rule;
                     withdrawer_updater => OWNER;
                 })
             .deposit_roles(deposit_roles! {
@@ -125,7 +256,7 @@ pub(crate) fn create(
     name: String,
     info_url: String,
     key_image_url: String,
-    dao_type: String,
+    dao_type: DaoType,
     governance_resource: ResourceAddress,
     about: String,
     general: String,
@@ -133,6 +264,8 @@ pub(crate) fn create(
     rules: Vec<String>,
     additional_data: HashMap<String, String>,
 ) -> Bucket {
+    dao_type.check_governance_resource(governance_resource);
+
     let info_unchecked_url = Url::of(info_url);
     let key_image_unchecked_url = Url::of(key_image_url);
     let proposal_resource_manager =
@@ -162,6 +295,10 @@ pub(crate) fn create(
         additional_data,
         additional_data_vec: HashMap::new(),
         proposals: proposal_resource_manager,
+        status: DaoStatus::Active,
+        frozen: false,
+        revision: 0,
+        updated_epoch: None,
     };
 
     let dao_non_fungible_id = utils::create_non_fungible_id_of_uuid(&dao_id);
@@ -169,65 +306,98 @@ pub(crate) fn create(
     dao_bucket
 }
 
-pub(crate) fn update(
+// only fields present in the patch and different from the current value are written.
+// returns the written fields
+pub(crate) fn patch(dao_resource_manager: ResourceManager, dao_id: &str, patch: DaoPatch) -> DaoPatch {
+    let dao = get(dao_resource_manager, dao_id);
+    let dao_type = patch.dao_type.unwrap_or(dao.dao_type);
+    let governance_resource = patch.governance_resource.unwrap_or(dao.governance_resource);
+    dao_type.check_governance_resource(governance_resource);
+
+    let applied = DaoPatch {
+        name: patch.name.filter(|name| *name != dao.name),
+        info_url: patch.info_url.filter(|info_url| Url::of(info_url.clone()) != dao.info_url),
+        key_image_url: patch.key_image_url.filter(|key_image_url| Url::of(key_image_url.clone()) != dao.key_image_url),
+        dao_type: Some(dao_type).filter(|dao_type| *dao_type != dao.dao_type),
+        governance_resource: Some(governance_resource).filter(|governance_resource| *governance_resource != dao.governance_resource),
+        about: patch.about.filter(|about| *about != dao.about || *about != dao.description),
+        general: patch.general.filter(|general| *general != dao.general),
+        rules: patch.rules.filter(|rules| *rules != dao.rules),
+        additional_data: patch.additional_data.filter(|additional_data| *additional_data != dao.additional_data),
+    };
+
+    let dao_non_fungible_id = utils::create_non_fungible_id_of_uuid(dao_id);
+    if let Some(name) = &applied.name {
+        dao_resource_manager.update_non_fungible_data(&dao_non_fungible_id, "name", name.clone());
+    }
+    if let Some(info_url) = &applied.info_url {
+        dao_resource_manager.update_non_fungible_data(&dao_non_fungible_id, "info_url", Url::of(info_url.clone()));
+    }
+    if let Some(key_image_url) = &applied.key_image_url {
+        dao_resource_manager.update_non_fungible_data(&dao_non_fungible_id, "key_image_url", Url::of(key_image_url.clone()));
+    }
+    if let Some(dao_type) = applied.dao_type {
+        dao_resource_manager.update_non_fungible_data(&dao_non_fungible_id, "dao_type", dao_type);
+    }
+    if let Some(governance_resource) = applied.governance_resource {
+        dao_resource_manager.update_non_fungible_data(&dao_non_fungible_id, "governance_resource", governance_resource);
+    }
+    if let Some(about) = &applied.about {
+        // description is what wallets show, it mirrors about
+        if *about != dao.description {
+            dao_resource_manager.update_non_fungible_data(&dao_non_fungible_id, "description", about.clone());
+        }
+        if *about != dao.about {
+            dao_resource_manager.update_non_fungible_data(&dao_non_fungible_id, "about", about.clone());
+        }
+    }
+    if let Some(general) = &applied.general {
+        dao_resource_manager.update_non_fungible_data(&dao_non_fungible_id, "general", general.clone());
+    }
+    if let Some(rules) = &applied.rules {
+        dao_resource_manager.update_non_fungible_data(&dao_non_fungible_id, "rules", rules.clone());
+    }
+    if let Some(additional_data) = &applied.additional_data {
+        dao_resource_manager.update_non_fungible_data(&dao_non_fungible_id, "additional_data", additional_data.clone());
+    }
+    applied
+}
+
+// every update that changed the DAO raises its revision, returns the new revision
+pub(crate) fn record_update(dao_resource_manager: ResourceManager, dao_id: &str) -> u64 {
+    let dao = get(dao_resource_manager, dao_id);
+    let revision = dao.revision + 1;
+
+    let dao_non_fungible_id = utils::create_non_fungible_id_of_uuid(dao_id);
+    dao_resource_manager.update_non_fungible_data(&dao_non_fungible_id, "revision", revision);
+    dao_resource_manager.update_non_fungible_data(&dao_non_fungible_id, "updated_epoch", Some(Runtime::current_epoch()));
+    revision
+}
+
+pub(crate) fn set_status(dao_resource_manager: ResourceManager, dao_id: &str, status: DaoStatus) {
+    let dao_non_fungible_id = utils::create_non_fungible_id_of_uuid(dao_id);
+    dao_resource_manager.update_non_fungible_data(&dao_non_fungible_id, "status", status);
+}
+
+pub(crate) fn set_frozen(dao_resource_manager: ResourceManager, dao_id: &str, frozen: bool) {
+    let dao_non_fungible_id = utils::create_non_fungible_id_of_uuid(dao_id);
+    dao_resource_manager.update_non_fungible_data(&dao_non_fungible_id, "frozen", frozen);
+}
+
+pub(crate) fn set_additional_data(
     dao_resource_manager: ResourceManager,
-    dao_id: String,
-    name: String,
-    info_url: String,
-    key_image_url: String,
-    dao_type: String,
-    governance_resource: ResourceAddress,
-    about: String,
-    general: String,
-    rules: Vec<String>,
+    dao_id: &str,
     additional_data: HashMap<String, String>,
+    additional_data_vec: HashMap<String, Vec<String>>,
 ) {
-    let dao_non_fungible_id = utils::create_non_fungible_id_of_uuid(&dao_id);
+    let dao_non_fungible_id = utils::create_non_fungible_id_of_uuid(dao_id);
+    dao_resource_manager.update_non_fungible_data(&dao_non_fungible_id, "additional_data", additional_data);
+    dao_resource_manager.update_non_fungible_data(&dao_non_fungible_id, "additional_data_vec", additional_data_vec);
+}
 
-    let info_unchecked_url = Url::of(info_url);
-    let key_image_unchecked_url = Url::of(key_image_url);
-    dao_resource_manager
-        .update_non_fungible_data(&dao_non_fungible_id, "name", name);
-    dao_resource_manager.update_non_fungible_data(
-        &dao_non_fungible_id,
-        "info_url",
-        info_unchecked_url,
-    );
-    dao_resource_manager.update_non_fungible_data(
-        &dao_non_fungible_id,
-        "key_image_url",
-        key_image_unchecked_url,
-    );
-    dao_resource_manager.update_non_fungible_data(
-        &dao_non_fungible_id,
-        "dao_type",
-        dao_type,
-    );
-    dao_resource_manager.update_non_fungible_data(
-        &dao_non_fungible_id,
-        "governance_resource",
-        governance_resource,
-    );
-    dao_resource_manager.update_non_fungible_data(
-        &dao_non_fungible_id,
-        "about",
-        about,
-    );
-    dao_resource_manager.update_non_fungible_data(
-        &dao_non_fungible_id,
-        "general",
-        general,
-    );
-    dao_resource_manager.update_non_fungible_data(
-        &dao_non_fungible_id,
-        "rules",
-        rules,
-    );
-    dao_resource_manager.update_non_fungible_data(
-        &dao_non_fungible_id,
-        "additional_data",
-        additional_data,
-    );
+pub(crate) fn set_version(dao_resource_manager: ResourceManager, dao_id: &str, version: u32) {
+    let dao_non_fungible_id = utils::create_non_fungible_id_of_uuid(dao_id);
+    dao_resource_manager.update_non_fungible_data(&dao_non_fungible_id, "version", version);
 }
 
 pub(crate) fn get(dao_resource_manager: ResourceManager, dao_id: &str) -> Dao {
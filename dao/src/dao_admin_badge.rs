@@ -3,8 +3,11 @@ use scrypto::prelude::*;
 pub(crate) fn create_dao_admin_badge_resource_manager(
     owner_badge_address: ResourceAddress,
     owner_badge_access_rule: &AccessRule,
-    component_address: ComponentAddress
+    component_address: ComponentAddress,
+    soulbound: bool,
 ) -> ResourceManager {
+    let withdrawer_rule = if soulbound { rule!(deny_all) } else { rule!(allow_all) };
+
     ResourceBuilder::new_ruid_non_fungible::<DaoAdminBadge>(OwnerRole::Fixed(owner_badge_access_rule.clone()))
         .metadata(metadata!(
                     roles {
@@ -37,7 +40,7 @@ pub(crate) fn create_dao_admin_badge_resource_manager(
                     burner_updater => OWNER;
                 })
         .withdraw_roles(withdraw_roles! {
-                    withdrawer => rule!(allow_all);
+                    withdrawer => withdrawer_rule;
                     withdrawer_updater => OWNER;
                 })
         .deposit_roles(deposit_roles! {
@@ -51,9 +54,48 @@ pub(crate) fn create_dao_admin_badge_resource_manager(
         .create_with_no_initial_supply()
 }
 
+#[derive(ScryptoSbor, PartialEq, Clone, Copy, Debug)]
+pub enum DaoAdminRole {
+    Owner,
+    Admin,
+    Moderator,
+    Proposer,
+}
+
+#[derive(ScryptoSbor, Clone, Debug)]
+pub struct DaoAdminBadgeView {
+    pub badge_id: NonFungibleLocalId,
+    pub role: DaoAdminRole,
+    pub expires_epoch: Option<Epoch>,
+}
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub(crate) enum DaoPermission {
+    ManageAdminBadges,
+    UpdateDao,
+    ManageTreasury,
+    CreateProposals,
+    AddOptions,
+    Moderate,
+}
+
+impl DaoAdminRole {
+    pub(crate) fn has_permission(&self, permission: DaoPermission) -> bool {
+        match self {
+            DaoAdminRole::Owner => true,
+            DaoAdminRole::Admin => permission != DaoPermission::ManageAdminBadges,
+            DaoAdminRole::Moderator => permission == DaoPermission::Moderate,
+            DaoAdminRole::Proposer => matches!(permission, DaoPermission::CreateProposals | DaoPermission::AddOptions),
+        }
+    }
+}
+
 #[derive(ScryptoSbor, NonFungibleData)]
 pub(crate) struct DaoAdminBadge {
     dao_id: String,
+    role: DaoAdminRole,
+    #[mutable]
+    expires_epoch: Option<Epoch>,
     #[mutable]
     name: String,
     #[mutable]
@@ -72,27 +114,314 @@ impl DaoAdminBadge {
     pub(crate) fn dao_id(&self) -> String {
         self.dao_id.clone()
     }
+
+    pub(crate) fn role(&self) -> DaoAdminRole {
+        self.role
+    }
+
+    pub(crate) fn is_expired(&self) -> bool {
+        match self.expires_epoch {
+            Some(expires_epoch) => Runtime::current_epoch().number() > expires_epoch.number(),
+            None => false,
+        }
+    }
+}
+
+pub(crate) fn mint(
+    dao_admin_badges_manager: ResourceManager,
+    dao_admin_badge_ids: &KeyValueStore<String, HashSet<NonFungibleLocalId>>,
+    dao_id: String,
+    dao_name: String,
+    dao_key_image_url: Url,
+    role: DaoAdminRole,
+    expires_epoch: Option<u64>,
+    max_admins: u32,
+) -> Bucket {
+    let admin_count = active_count(dao_admin_badge_ids, &dao_id);
+    assert!((admin_count as u32) < max_admins, "DAO {} already has the maximum of {} admins", dao_id, max_admins);
+
+    mint_badge(dao_admin_badges_manager, dao_admin_badge_ids, dao_id, dao_name, dao_key_image_url, role, checked_expires_epoch(expires_epoch))
 }
 
-pub(crate) fn mint(dao_admin_badges_manager: ResourceManager, dao_id: String, dao_name: String) -> Bucket {
+fn mint_badge(
+    dao_admin_badges_manager: ResourceManager,
+    dao_admin_badge_ids: &KeyValueStore<String, HashSet<NonFungibleLocalId>>,
+    dao_id: String,
+    dao_name: String,
+    dao_key_image_url: Url,
+    role: DaoAdminRole,
+    expires_epoch: Option<Epoch>,
+) -> Bucket {
     let dao_admin_badge = DaoAdminBadge {
-        dao_id,
-        name: format!("{} Admin Badge", dao_name),
-        description: format!("This Admin Badge allows you to administrate the DAO {}.", dao_name),
+        dao_id: dao_id.clone(),
+        role,
+        expires_epoch,
+        name: badge_name(&dao_name),
+        description: badge_description(&dao_name),
         info_url: Url::of("https://crumbsup.io"),
-        key_image_url: Url::of("https://arweave.net/RkZVcWWW0KzhzNggBXsz54T3tMDlerRwMDaobNSIPgk"),
+        key_image_url: dao_key_image_url,
         additional_data: HashMap::new(),
         additional_data_vec: HashMap::new(),
     };
 
     let dao_admin_badge_bucket = dao_admin_badges_manager.mint_ruid_non_fungible(dao_admin_badge);
+    register(dao_admin_badge_ids, dao_id, dao_admin_badge_bucket.as_non_fungible().non_fungible_local_id());
+    dao_admin_badge_bucket
+}
+
+// moves a badge to a new holder: the old badge is recalled and burned and a copy with the same role is minted
+pub(crate) fn reissue(
+    dao_admin_badges_manager: ResourceManager,
+    dao_admin_badge_ids: &KeyValueStore<String, HashSet<NonFungibleLocalId>>,
+    dao_id: &String,
+    badge_id: &NonFungibleLocalId,
+    holder_vault: InternalAddress,
+    dao_name: String,
+    dao_key_image_url: Url,
+    approval_threshold: u32,
+) -> Bucket {
+    assert!(is_active(dao_admin_badge_ids, dao_id, badge_id), "Admin badge {} is not an active badge of DAO {}", badge_id.to_string(), dao_id);
+    let dao_admin_badge: DaoAdminBadge = dao_admin_badges_manager.get_non_fungible_data(badge_id);
+
+    let dao_admin_badge_bucket = mint_badge(dao_admin_badges_manager, dao_admin_badge_ids, dao_id.clone(), dao_name, dao_key_image_url, dao_admin_badge.role, dao_admin_badge.expires_epoch);
+    revoke(dao_admin_badges_manager, dao_admin_badge_ids, dao_id, badge_id, holder_vault, approval_threshold);
     dao_admin_badge_bucket
 }
 
-pub(crate) fn check_is_dao_admin(dao_admin_badges_manager: ResourceManager, admin_badges: Proof, dao_id: &String) {
+pub(crate) fn renew(
+    dao_admin_badges_manager: ResourceManager,
+    dao_admin_badge_ids: &KeyValueStore<String, HashSet<NonFungibleLocalId>>,
+    dao_id: &String,
+    badge_id: &NonFungibleLocalId,
+    expires_epoch: Option<u64>,
+) {
+    assert!(is_active(dao_admin_badge_ids, dao_id, badge_id), "Admin badge {} is not an active badge of DAO {}", badge_id.to_string(), dao_id);
+
+    dao_admin_badges_manager.update_non_fungible_data(
+        badge_id,
+        "expires_epoch",
+        checked_expires_epoch(expires_epoch),
+    );
+}
+
+// keeps the active badges of a DAO in line with its current name and logo
+pub(crate) fn rebrand(
+    dao_admin_badges_manager: ResourceManager,
+    dao_admin_badge_ids: &KeyValueStore<String, HashSet<NonFungibleLocalId>>,
+    dao_id: &String,
+    dao_name: &str,
+    dao_key_image_url: Url,
+) {
+    let badge_ids: Vec<NonFungibleLocalId> = match dao_admin_badge_ids.get(dao_id) {
+        Some(badge_ids) => badge_ids.iter().cloned().collect(),
+        None => Vec::new(),
+    };
+
+    for badge_id in badge_ids.iter() {
+        dao_admin_badges_manager.update_non_fungible_data(badge_id, "name", badge_name(dao_name));
+        dao_admin_badges_manager.update_non_fungible_data(badge_id, "description", badge_description(dao_name));
+        dao_admin_badges_manager.update_non_fungible_data(badge_id, "key_image_url", dao_key_image_url.clone());
+    }
+}
+
+fn badge_name(dao_name: &str) -> String {
+    format!("{} Admin Badge", dao_name)
+}
+
+fn badge_description(dao_name: &str) -> String {
+    format!("This Admin Badge allows you to administrate the DAO {}.", dao_name)
+}
+
+fn checked_expires_epoch(expires_epoch: Option<u64>) -> Option<Epoch> {
+    expires_epoch.map(|expires_epoch| {
+        let current_epoch = Runtime::current_epoch().number();
+        assert!(current_epoch < expires_epoch, "Expiry epoch {} is in the past. Current epoch {}", expires_epoch, current_epoch);
+        Epoch::of(expires_epoch)
+    })
+}
+
+fn register(dao_admin_badge_ids: &KeyValueStore<String, HashSet<NonFungibleLocalId>>, dao_id: String, badge_id: NonFungibleLocalId) {
+    if let Some(mut badge_ids) = dao_admin_badge_ids.get_mut(&dao_id) {
+        badge_ids.insert(badge_id);
+        return;
+    }
+    dao_admin_badge_ids.insert(dao_id, HashSet::from([badge_id]));
+}
+
+pub(crate) fn is_active(dao_admin_badge_ids: &KeyValueStore<String, HashSet<NonFungibleLocalId>>, dao_id: &String, badge_id: &NonFungibleLocalId) -> bool {
+    match dao_admin_badge_ids.get(dao_id) {
+        Some(badge_ids) => badge_ids.contains(badge_id),
+        None => false,
+    }
+}
+
+pub(crate) fn active_count(dao_admin_badge_ids: &KeyValueStore<String, HashSet<NonFungibleLocalId>>, dao_id: &String) -> usize {
+    match dao_admin_badge_ids.get(dao_id) {
+        Some(badge_ids) => badge_ids.len(),
+        None => 0,
+    }
+}
+
+// usable badges are active and not expired, only they can approve operations
+pub(crate) fn is_usable(
+    dao_admin_badges_manager: ResourceManager,
+    dao_admin_badge_ids: &KeyValueStore<String, HashSet<NonFungibleLocalId>>,
+    dao_id: &String,
+    badge_id: &NonFungibleLocalId,
+) -> bool {
+    if !is_active(dao_admin_badge_ids, dao_id, badge_id) {
+        return false;
+    }
+    let dao_admin_badge: DaoAdminBadge = dao_admin_badges_manager.get_non_fungible_data(badge_id);
+    !dao_admin_badge.is_expired()
+}
+
+pub(crate) fn usable_count(
+    dao_admin_badges_manager: ResourceManager,
+    dao_admin_badge_ids: &KeyValueStore<String, HashSet<NonFungibleLocalId>>,
+    dao_id: &String,
+) -> usize {
+    let badge_ids: Vec<NonFungibleLocalId> = match dao_admin_badge_ids.get(dao_id) {
+        Some(badge_ids) => badge_ids.iter().cloned().collect(),
+        None => Vec::new(),
+    };
+
+    badge_ids.iter()
+        .filter(|badge_id| {
+            let dao_admin_badge: DaoAdminBadge = dao_admin_badges_manager.get_non_fungible_data(badge_id);
+            !dao_admin_badge.is_expired()
+        })
+        .count()
+}
+
+// removing badges must leave enough usable badges to reach the approval threshold
+fn check_enough_admins(
+    dao_admin_badges_manager: ResourceManager,
+    dao_admin_badge_ids: &KeyValueStore<String, HashSet<NonFungibleLocalId>>,
+    dao_id: &String,
+    approval_threshold: u32,
+) {
+    let usable_count = usable_count(dao_admin_badges_manager, dao_admin_badge_ids, dao_id);
+    let required_count = approval_threshold.max(1) as usize;
+    assert!(usable_count >= required_count, "DAO {} needs at least {} usable admin badges for its approval threshold, {} would be left", dao_id, required_count, usable_count);
+}
+
+pub(crate) fn list(
+    dao_admin_badges_manager: ResourceManager,
+    dao_admin_badge_ids: &KeyValueStore<String, HashSet<NonFungibleLocalId>>,
+    dao_id: &String,
+) -> Vec<DaoAdminBadgeView> {
+    let badge_ids: Vec<NonFungibleLocalId> = match dao_admin_badge_ids.get(dao_id) {
+        Some(badge_ids) => badge_ids.iter().cloned().collect(),
+        None => Vec::new(),
+    };
+
+    badge_ids.into_iter().map(|badge_id| {
+        let dao_admin_badge: DaoAdminBadge = dao_admin_badges_manager.get_non_fungible_data(&badge_id);
+        DaoAdminBadgeView {
+            badge_id,
+            role: dao_admin_badge.role,
+            expires_epoch: dao_admin_badge.expires_epoch,
+        }
+    }).collect()
+}
+
+// the caller names the vault holding the badge, the badge is recalled from it and burned
+pub(crate) fn revoke(
+    dao_admin_badges_manager: ResourceManager,
+    dao_admin_badge_ids: &KeyValueStore<String, HashSet<NonFungibleLocalId>>,
+    dao_id: &String,
+    badge_id: &NonFungibleLocalId,
+    holder_vault: InternalAddress,
+    approval_threshold: u32,
+) {
+    {
+        let mut badge_ids = dao_admin_badge_ids.get_mut(dao_id)
+            .unwrap_or_else(|| panic!("DAO {} has no admin badges", dao_id));
+        assert!(badge_ids.remove(badge_id), "Admin badge {} is not an active badge of DAO {}", badge_id.to_string(), dao_id);
+        assert!(!badge_ids.is_empty(), "The last admin badge of DAO {} can not be revoked", dao_id);
+    }
+
+    let dao_admin_badge: DaoAdminBadge = dao_admin_badges_manager.get_non_fungible_data(badge_id);
+    if !dao_admin_badge.is_expired() {
+        check_enough_admins(dao_admin_badges_manager, dao_admin_badge_ids, dao_id, approval_threshold);
+    }
+    recall(dao_admin_badges_manager, holder_vault, badge_id).burn();
+}
+
+fn recall(dao_admin_badges_manager: ResourceManager, holder_vault: InternalAddress, badge_id: &NonFungibleLocalId) -> Bucket {
+    let mut badge_ids = IndexSet::new();
+    badge_ids.insert(badge_id.clone());
+    let recalled: Bucket = scrypto_decode(&ScryptoVmV1Api::object_call_direct(
+        holder_vault.as_node_id(),
+        NON_FUNGIBLE_VAULT_RECALL_NON_FUNGIBLES_IDENT,
+        scrypto_args!(badge_ids),
+    )).unwrap();
+    assert_eq!(recalled.resource_address(), dao_admin_badges_manager.address(), "Vault {:?} holds no DAO Admin Badges", holder_vault);
+    recalled
+}
+
+pub(crate) fn burn(
+    dao_admin_badges_manager: ResourceManager,
+    dao_admin_badge_ids: &KeyValueStore<String, HashSet<NonFungibleLocalId>>,
+    dao_admin_badges: Bucket,
+    approval_threshold: impl Fn(&String) -> u32,
+) {
+    assert_eq!(dao_admin_badges.resource_address(), dao_admin_badges_manager.address(), "NFTs are no DAO Admin Badges");
+
+    let mut dao_ids: HashSet<String> = HashSet::new();
+    for nft in dao_admin_badges.as_non_fungible().non_fungibles::<DaoAdminBadge>().iter() {
+        let dao_admin_badge = nft.data();
+        if let Some(mut badge_ids) = dao_admin_badge_ids.get_mut(&dao_admin_badge.dao_id()) {
+            if badge_ids.remove(nft.local_id()) && !dao_admin_badge.is_expired() {
+                dao_ids.insert(dao_admin_badge.dao_id());
+            }
+        }
+    }
+    for dao_id in dao_ids.iter() {
+        check_enough_admins(dao_admin_badges_manager, dao_admin_badge_ids, dao_id, approval_threshold(dao_id));
+    }
+    dao_admin_badges.burn();
+}
+
+pub(crate) fn check_is_dao_admin(
+    dao_admin_badges_manager: ResourceManager,
+    dao_admin_badge_ids: &KeyValueStore<String, HashSet<NonFungibleLocalId>>,
+    admin_badges: Proof,
+    dao_id: &String,
+    permission: DaoPermission,
+) -> Vec<NonFungibleLocalId> {
     let admin_badges_checked = admin_badges.check_with_message(dao_admin_badges_manager.address().clone(), "NFTs are no DAO Admin Badges").as_non_fungible();//.non_fungible_local_ids();
-    let dao_admin_badge_found = admin_badges_checked.non_fungibles().iter().any(|nft: &NonFungible<DaoAdminBadge>| {
-        *dao_id == nft.data().dao_id()
-    });
-    assert!(dao_admin_badge_found, "You are not an admin of this DAO");
+    find_dao_admin_badges(&admin_badges_checked, dao_admin_badge_ids, dao_id, permission)
+}
+
+// approvals of operations are counted per badge, so every badge has to approve with its own proof
+pub(crate) fn check_is_single_dao_admin(
+    dao_admin_badges_manager: ResourceManager,
+    dao_admin_badge_ids: &KeyValueStore<String, HashSet<NonFungibleLocalId>>,
+    admin_badge: Proof,
+    dao_id: &String,
+    permission: DaoPermission,
+) -> NonFungibleLocalId {
+    let admin_badge_checked = admin_badge.check_with_message(dao_admin_badges_manager.address().clone(), "NFTs are no DAO Admin Badges").as_non_fungible();
+    let badge_count = admin_badge_checked.non_fungible_local_ids().len();
+    assert_eq!(badge_count, 1, "Operations are submitted and approved with exactly one admin badge. Provided {}", badge_count);
+    find_dao_admin_badges(&admin_badge_checked, dao_admin_badge_ids, dao_id, permission).remove(0)
+}
+
+fn find_dao_admin_badges(
+    admin_badges_checked: &CheckedNonFungibleProof,
+    dao_admin_badge_ids: &KeyValueStore<String, HashSet<NonFungibleLocalId>>,
+    dao_id: &String,
+    permission: DaoPermission,
+) -> Vec<NonFungibleLocalId> {
+    let dao_admin_badge_ids_found: Vec<NonFungibleLocalId> = admin_badges_checked.non_fungibles().iter()
+        .filter(|nft: &&NonFungible<DaoAdminBadge>| {
+            let dao_admin_badge = nft.data();
+            *dao_id == dao_admin_badge.dao_id() && is_active(dao_admin_badge_ids, dao_id, nft.local_id()) && !dao_admin_badge.is_expired() && dao_admin_badge.role().has_permission(permission)
+        })
+        .map(|nft| nft.local_id().clone())
+        .collect();
+    assert!(!dao_admin_badge_ids_found.is_empty(), "You are not an admin of this DAO with {:?} permission", permission);
+    dao_admin_badge_ids_found
 }
@@ -0,0 +1,72 @@
+use scrypto::prelude::*;
+
+use crate::dao::DaoType;
+use crate::validation;
+
+#[derive(ScryptoSbor, PartialEq, Clone, Copy, Debug)]
+pub enum DaoApplicationStatus {
+    Pending,
+    Approved,
+    Rejected,
+}
+
+#[derive(ScryptoSbor, Clone)]
+pub(crate) struct DaoApplicationData {
+    pub(crate) name: String,
+    pub(crate) info_url: String,
+    pub(crate) key_image_url: String,
+    pub(crate) dao_type: DaoType,
+    pub(crate) governance_resource: ResourceAddress,
+    pub(crate) about: String,
+    pub(crate) general: String,
+    pub(crate) created: String,
+    pub(crate) rules: Vec<String>,
+    pub(crate) additional_data: HashMap<String, String>,
+}
+
+#[derive(ScryptoSbor)]
+pub(crate) struct DaoApplication {
+    data: DaoApplicationData,
+    account: ComponentAddress,
+    fee: Vault,
+    status: DaoApplicationStatus,
+}
+
+impl DaoApplication {
+    pub(crate) fn status(&self) -> DaoApplicationStatus {
+        self.status
+    }
+}
+
+pub(crate) fn check(dao_id: &str, data: &DaoApplicationData) {
+    validation::check_uuid("DAO id", dao_id);
+    validation::check_dao(&data.name, &data.info_url, &data.key_image_url, &data.about, &data.general, &data.rules, &data.additional_data);
+    validation::check_timestamp("Created", &data.created);
+    data.dao_type.check_governance_resource(data.governance_resource);
+}
+
+// the fee stays escrowed until the application is decided
+pub(crate) fn submit(data: DaoApplicationData, account: Global<Account>, fee: Bucket) -> DaoApplication {
+    DaoApplication {
+        data,
+        account: account.address(),
+        fee: Vault::with_bucket(fee),
+        status: DaoApplicationStatus::Pending,
+    }
+}
+
+pub(crate) fn approve(application: &mut DaoApplication, dao_id: &String) -> (DaoApplicationData, ComponentAddress, Bucket) {
+    check_pending(application, dao_id);
+    application.status = DaoApplicationStatus::Approved;
+    (application.data.clone(), application.account, application.fee.take_all())
+}
+
+pub(crate) fn reject(application: &mut DaoApplication, dao_id: &String) -> (ComponentAddress, Bucket) {
+    check_pending(application, dao_id);
+    application.status = DaoApplicationStatus::Rejected;
+    (application.account, application.fee.take_all())
+}
+
+fn check_pending(application: &DaoApplication, dao_id: &String) {
+    assert_eq!(application.status, DaoApplicationStatus::Pending, "Application for DAO {} has already been decided", dao_id);
+}
@@ -0,0 +1,115 @@
+use scrypto::prelude::*;
+
+use crate::additional_data::AdditionalDataChange;
+use crate::dao::{DaoPatch, DaoStatus};
+use crate::dao_admin_badge::{DaoAdminRole, DaoPermission};
+use crate::dao_settings::DepositSlashTarget;
+
+#[derive(ScryptoSbor, Clone, Debug)]
+pub enum DaoOperation {
+    UpdateDao {
+        patch: DaoPatch,
+    },
+    UpdateAdditionalData {
+        changes: Vec<AdditionalDataChange>,
+    },
+    MintAdminBadge {
+        role: DaoAdminRole,
+        expires_epoch: Option<u64>,
+    },
+    RevokeAdminBadge {
+        badge_id: NonFungibleLocalId,
+        holder_vault: InternalAddress,
+    },
+    ReissueAdminBadge {
+        badge_id: NonFungibleLocalId,
+        holder_vault: InternalAddress,
+    },
+    RenewAdminBadge {
+        badge_id: NonFungibleLocalId,
+        expires_epoch: Option<u64>,
+    },
+    WithdrawTreasury {
+        resource_address: ResourceAddress,
+    },
+    SetProposalDeposit {
+        proposal_deposit: Decimal,
+        quorum: Decimal,
+        deposit_slash_target: DepositSlashTarget,
+    },
+    SetMemberProposals {
+        member_proposal_threshold: Option<Decimal>,
+        member_proposal_sponsorship: bool,
+        member_proposal_seconds: u32,
+    },
+    SetApprovalPolicy {
+        approval_threshold: u32,
+        approval_expiry_epochs: u64,
+    },
+    SetStatus {
+        status: DaoStatus,
+    },
+}
+
+impl DaoOperation {
+    pub(crate) fn permission(&self) -> DaoPermission {
+        match self {
+            DaoOperation::UpdateDao { .. } => DaoPermission::UpdateDao,
+            DaoOperation::UpdateAdditionalData { .. } => DaoPermission::UpdateDao,
+            DaoOperation::MintAdminBadge { .. } => DaoPermission::ManageAdminBadges,
+            DaoOperation::RevokeAdminBadge { .. } => DaoPermission::ManageAdminBadges,
+            DaoOperation::ReissueAdminBadge { .. } => DaoPermission::ManageAdminBadges,
+            DaoOperation::RenewAdminBadge { .. } => DaoPermission::ManageAdminBadges,
+            DaoOperation::WithdrawTreasury { .. } => DaoPermission::ManageTreasury,
+            DaoOperation::SetProposalDeposit { .. } => DaoPermission::UpdateDao,
+            DaoOperation::SetMemberProposals { .. } => DaoPermission::UpdateDao,
+            DaoOperation::SetApprovalPolicy { .. } => DaoPermission::ManageAdminBadges,
+            DaoOperation::SetStatus { .. } => DaoPermission::UpdateDao,
+        }
+    }
+}
+
+#[derive(ScryptoSbor)]
+pub(crate) struct PendingDaoOperation {
+    dao_id: String,
+    operation: DaoOperation,
+    approvals: HashSet<NonFungibleLocalId>,
+    expires_epoch: Epoch,
+}
+
+impl PendingDaoOperation {
+    pub(crate) fn operation(&self) -> DaoOperation {
+        self.operation.clone()
+    }
+
+    // approvals of badges revoked or expired in the meantime do not count
+    pub(crate) fn approval_count(&self, is_usable: impl Fn(&NonFungibleLocalId) -> bool) -> u32 {
+        self.approvals.iter().filter(|badge_id| is_usable(badge_id)).count() as u32
+    }
+}
+
+pub(crate) fn submit(
+    dao_id: String,
+    operation: DaoOperation,
+    approval_expiry_epochs: u64,
+    admin_badge_id: NonFungibleLocalId,
+) -> PendingDaoOperation {
+    let current_epoch = Runtime::current_epoch().number();
+    let pending_dao_operation = PendingDaoOperation {
+        dao_id,
+        operation,
+        approvals: HashSet::from([admin_badge_id]),
+        expires_epoch: Epoch::of(current_epoch + approval_expiry_epochs),
+    };
+
+    pending_dao_operation
+}
+
+pub(crate) fn approve(pending_dao_operation: &mut PendingDaoOperation, dao_id: &String, admin_badge_id: NonFungibleLocalId) {
+    assert_eq!(pending_dao_operation.dao_id, *dao_id, "Operation does not belong to DAO {}", dao_id);
+
+    let current_epoch = Runtime::current_epoch().number();
+    assert!(current_epoch <= pending_dao_operation.expires_epoch.number(), "Operation expired at epoch {}. Current epoch {}", pending_dao_operation.expires_epoch.number(), current_epoch);
+
+    assert!(pending_dao_operation.approvals.insert(admin_badge_id.clone()), "Admin badge {} already approved the operation", admin_badge_id.to_string());
+}
@@ -0,0 +1,103 @@
+use scrypto::prelude::*;
+
+pub(crate) const MAX_PAGE_SIZE: u64 = 100;
+
+#[derive(ScryptoSbor, Clone, Debug)]
+pub struct DaoRegistryEntry {
+    pub dao_id: String,
+    pub name: String,
+    pub slug: String,
+    pub created_epoch: Epoch,
+}
+
+#[derive(ScryptoSbor)]
+pub(crate) struct DaoRegistry {
+    entries: KeyValueStore<String, DaoRegistryEntry>,
+    dao_ids_by_slug: KeyValueStore<String, String>,
+    dao_ids_by_index: KeyValueStore<u64, String>,
+    count: u64,
+}
+
+pub(crate) fn create() -> DaoRegistry {
+    DaoRegistry {
+        entries: KeyValueStore::new(),
+        dao_ids_by_slug: KeyValueStore::new(),
+        dao_ids_by_index: KeyValueStore::new(),
+        count: 0,
+    }
+}
+
+pub(crate) fn check_available(dao_registry: &DaoRegistry, dao_id: &String, name: &str) {
+    assert!(dao_registry.entries.get(dao_id).is_none(), "DAO with id {} already exists", dao_id);
+    check_slug_available(dao_registry, &slug_of(name));
+}
+
+pub(crate) fn register(dao_registry: &mut DaoRegistry, dao_id: &String, name: &str) {
+    check_available(dao_registry, dao_id, name);
+    let slug = slug_of(name);
+
+    dao_registry.dao_ids_by_slug.insert(slug.clone(), dao_id.clone());
+    dao_registry.dao_ids_by_index.insert(dao_registry.count, dao_id.clone());
+    dao_registry.count += 1;
+    dao_registry.entries.insert(dao_id.clone(), DaoRegistryEntry {
+        dao_id: dao_id.clone(),
+        name: name.to_string(),
+        slug,
+        created_epoch: Runtime::current_epoch(),
+    });
+}
+
+pub(crate) fn rename(dao_registry: &mut DaoRegistry, dao_id: &String, name: &str) {
+    let mut entry = dao_registry.entries.get(dao_id)
+        .unwrap_or_else(|| panic!("DAO with id {} is not registered", dao_id))
+        .clone();
+
+    let slug = slug_of(name);
+    if slug != entry.slug {
+        check_slug_available(dao_registry, &slug);
+        dao_registry.dao_ids_by_slug.remove(&entry.slug);
+        dao_registry.dao_ids_by_slug.insert(slug.clone(), dao_id.clone());
+        entry.slug = slug;
+    }
+    entry.name = name.to_string();
+    dao_registry.entries.insert(dao_id.clone(), entry);
+}
+
+pub(crate) fn find_by_slug(dao_registry: &DaoRegistry, slug: &String) -> Option<DaoRegistryEntry> {
+    let dao_id = dao_registry.dao_ids_by_slug.get(slug)?.clone();
+    dao_registry.entries.get(&dao_id).map(|entry| entry.clone())
+}
+
+pub(crate) fn count(dao_registry: &DaoRegistry) -> u64 {
+    dao_registry.count
+}
+
+pub(crate) fn dao_ids(dao_registry: &DaoRegistry, offset: u64, limit: u64) -> Vec<String> {
+    list(dao_registry, offset, limit).into_iter().map(|entry| entry.dao_id).collect()
+}
+
+pub(crate) fn list(dao_registry: &DaoRegistry, offset: u64, limit: u64) -> Vec<DaoRegistryEntry> {
+    assert!(limit <= MAX_PAGE_SIZE, "At most {} DAOs can be listed at once", MAX_PAGE_SIZE);
+
+    let end = dao_registry.count.min(offset.saturating_add(limit));
+    (offset..end)
+        .filter_map(|index| dao_registry.dao_ids_by_index.get(&index).map(|dao_id| dao_id.clone()))
+        .filter_map(|dao_id| dao_registry.entries.get(&dao_id).map(|entry| entry.clone()))
+        .collect()
+}
+
+// lowercase alphanumerics joined by single dashes, e.g. "My  DAO!" -> "my-dao"
+pub(crate) fn slug_of(name: &str) -> String {
+    let slug = name
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<&str>>()
+        .join("-");
+    assert!(!slug.is_empty(), "DAO name {} contains no letters or digits", name);
+    slug
+}
+
+fn check_slug_available(dao_registry: &DaoRegistry, slug: &String) {
+    assert!(dao_registry.dao_ids_by_slug.get(slug).is_none(), "DAO with name {} already exists", slug);
+}
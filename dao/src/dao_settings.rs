@@ -0,0 +1,47 @@
+use scrypto::prelude::*;
+
+use crate::proposal_fee::ProposalFeeOverride;
+
+#[derive(ScryptoSbor, PartialEq, Clone, Copy, Debug)]
+pub enum DepositSlashTarget {
+    DaoTreasury,
+    PlatformFees,
+}
+
+#[derive(ScryptoSbor, Clone, Debug)]
+pub struct DaoSettings {
+    pub proposal_deposit: Decimal,
+    pub quorum: Decimal,
+    pub deposit_slash_target: DepositSlashTarget,
+    pub member_proposal_threshold: Option<Decimal>,
+    pub member_proposal_sponsorship: bool,
+    pub member_proposal_seconds: u32,
+    pub approval_threshold: u32,
+    pub approval_expiry_epochs: u64,
+    pub max_admins: Option<u32>,
+    pub proposal_fee_override: Option<ProposalFeeOverride>,
+}
+
+impl Default for DaoSettings {
+    fn default() -> Self {
+        Self {
+            proposal_deposit: Decimal::zero(),
+            quorum: Decimal::zero(),
+            deposit_slash_target: DepositSlashTarget::DaoTreasury,
+            member_proposal_threshold: None,
+            member_proposal_sponsorship: false,
+            member_proposal_seconds: 0,
+            approval_threshold: 1,
+            approval_expiry_epochs: 2016,
+            max_admins: None,
+            proposal_fee_override: None,
+        }
+    }
+}
+
+pub(crate) fn get(dao_settings: &KeyValueStore<String, DaoSettings>, dao_id: &String) -> DaoSettings {
+    match dao_settings.get(dao_id) {
+        Some(settings) => settings.clone(),
+        None => DaoSettings::default(),
+    }
+}
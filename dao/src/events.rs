@@ -0,0 +1,283 @@
+use scrypto::prelude::*;
+
+use crate::additional_data::AdditionalDataChange;
+use crate::dao::{DaoPatch, DaoStatus};
+use crate::dao_admin_badge::DaoAdminRole;
+use crate::dao_settings::{DaoSettings, DepositSlashTarget};
+use crate::fee_distribution::FeeDistribution;
+use crate::proposal::{ProposalStatus, VotingTimes};
+use crate::proposal_fee::ProposalFeeOverride;
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct DaoCreated {
+    pub dao_id: String,
+    pub name: String,
+    pub governance_resource: ResourceAddress,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct DaoApplied {
+    pub dao_id: String,
+    pub name: String,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct DaoApplicationApproved {
+    pub dao_id: String,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct DaoApplicationRejected {
+    pub dao_id: String,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct DaoCreationPolicyChanged {
+    pub dao_creation_price: Decimal,
+    pub approval_required: bool,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct DaoUpdated {
+    pub dao_id: String,
+    pub revision: u64,
+    // only the fields that changed
+    pub patch: DaoPatch,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct DaoAdditionalDataUpdated {
+    pub dao_id: String,
+    pub revision: u64,
+    pub changes: Vec<AdditionalDataChange>,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct DaoStatusChanged {
+    pub dao_id: String,
+    pub status: DaoStatus,
+    pub frozen: bool,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct DaoSettingsUpdated {
+    pub dao_id: String,
+    pub settings: DaoSettings,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct AdminBadgeMinted {
+    pub dao_id: String,
+    pub badge_id: NonFungibleLocalId,
+    pub role: DaoAdminRole,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct AdminBadgeRevoked {
+    pub dao_id: String,
+    pub badge_id: NonFungibleLocalId,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct AdminBadgeRenewed {
+    pub dao_id: String,
+    pub badge_id: NonFungibleLocalId,
+    pub expires_epoch: Option<u64>,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct AdminBadgesBurned {
+    pub badge_ids: Vec<NonFungibleLocalId>,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct OperationSubmitted {
+    pub dao_id: String,
+    pub operation_id: u64,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct OperationApproved {
+    pub dao_id: String,
+    pub operation_id: u64,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct OperationExecuted {
+    pub dao_id: String,
+    pub operation_id: u64,
+}
+
+#[derive(ScryptoSbor, Clone, Debug)]
+pub enum Proposer {
+    Admin { badge_id: NonFungibleLocalId },
+    Member { governance_nfts: Vec<NonFungibleLocalId>, governance_amount: Decimal },
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct ProposalCreated {
+    pub dao_id: String,
+    pub proposal_id: String,
+    pub title: String,
+    pub status: ProposalStatus,
+    pub proposer: Proposer,
+    pub voting_start_epoch: Option<u64>,
+    pub voting_end_epoch: Option<u64>,
+    pub voting_times: Option<VotingTimes>,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct ProposalUpdated {
+    pub dao_id: String,
+    pub proposal_id: String,
+    pub info_url: Option<Url>,
+    pub key_image_url: Option<Url>,
+    pub additional_data: Option<HashMap<String, String>>,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct ProposalAdditionalDataUpdated {
+    pub dao_id: String,
+    pub proposal_id: String,
+    pub changes: Vec<AdditionalDataChange>,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct ProposalActivated {
+    pub dao_id: String,
+    pub proposal_id: String,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct ProposalSeconded {
+    pub dao_id: String,
+    pub proposal_id: String,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct ProposalCancelled {
+    pub dao_id: String,
+    pub proposal_id: String,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct OptionAdded {
+    pub dao_id: String,
+    pub proposal_id: String,
+    pub proposal_option_id: String,
+    pub rank: u32,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct VoteCast {
+    pub dao_id: String,
+    pub proposal_id: String,
+    pub proposal_vote_id: String,
+    pub proposal_option_id: String,
+    pub entity: ComponentAddress,
+    pub power: Decimal,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct ResultUpdated {
+    pub dao_id: String,
+    pub proposal_id: String,
+    pub vote_count: u32,
+    pub vote_power: Decimal,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct RewardClaimed {
+    pub dao_id: String,
+    pub proposal_id: String,
+    pub proposal_vote_id: String,
+    pub amount: Decimal,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct RewardSwept {
+    pub dao_id: String,
+    pub proposal_id: String,
+    pub amount: Decimal,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct DepositRefunded {
+    pub dao_id: String,
+    pub proposal_id: String,
+    pub amount: Decimal,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct DepositSlashed {
+    pub dao_id: String,
+    pub proposal_id: String,
+    pub amount: Decimal,
+    pub slash_target: DepositSlashTarget,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct TreasuryWithdrawn {
+    pub dao_id: String,
+    pub resource_address: ResourceAddress,
+    pub amount: Decimal,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct FeesWithdrawn {
+    pub resource_address: ResourceAddress,
+    pub amount: Decimal,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct FeesDistributed {
+    pub dao_id: String,
+    pub resource_address: ResourceAddress,
+    pub platform_amount: Decimal,
+    pub dao_treasury_amount: Decimal,
+    pub burned_amount: Decimal,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct FeeDistributionChanged {
+    pub fee_distribution: FeeDistribution,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct PriceChanged {
+    pub proposal_creation_price: Decimal,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct FeeResourceChanged {
+    pub resource_address: ResourceAddress,
+    pub price: Option<Decimal>,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct ProposalFeeOverrideChanged {
+    pub dao_id: String,
+    pub fee_override: Option<ProposalFeeOverride>,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct DaosMigrated {
+    pub dao_ids: Vec<String>,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct ProposalsMigrated {
+    pub dao_id: String,
+    pub proposal_ids: Vec<String>,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct MaxAdminsChanged {
+    pub dao_id: Option<String>,
+    pub max_admins: Option<u32>,
+}
+
+pub(crate) fn emit_admin_badge_minted(dao_id: String, dao_admin_badge_bucket: &Bucket, role: DaoAdminRole) {
+    let badge_id = dao_admin_badge_bucket.as_non_fungible().non_fungible_local_id();
+    Runtime::emit_event(AdminBadgeMinted { dao_id, badge_id, role });
+}
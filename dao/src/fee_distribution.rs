@@ -0,0 +1,79 @@
+use scrypto::prelude::*;
+
+#[derive(ScryptoSbor, PartialEq, Clone, Copy, Debug)]
+pub struct FeeDistribution {
+    pub platform_percentage: Decimal,
+    pub dao_treasury_percentage: Decimal,
+    pub burn_percentage: Decimal,
+}
+
+impl Default for FeeDistribution {
+    fn default() -> Self {
+        Self {
+            platform_percentage: dec!("100"),
+            dao_treasury_percentage: Decimal::zero(),
+            burn_percentage: Decimal::zero(),
+        }
+    }
+}
+
+#[derive(ScryptoSbor, Clone, Debug, Default)]
+pub struct DaoFeeRecord {
+    pub collected: HashMap<ResourceAddress, Decimal>,
+    pub to_platform: HashMap<ResourceAddress, Decimal>,
+    pub to_dao_treasury: HashMap<ResourceAddress, Decimal>,
+    pub burned: HashMap<ResourceAddress, Decimal>,
+}
+
+impl DaoFeeRecord {
+    pub(crate) fn resources(&self) -> Vec<ResourceAddress> {
+        self.collected.keys().cloned().collect()
+    }
+}
+
+pub(crate) struct FeeShares {
+    pub(crate) platform: Bucket,
+    pub(crate) dao_treasury: Bucket,
+    pub(crate) burn: Bucket,
+}
+
+pub(crate) fn check(fee_distribution: &FeeDistribution) {
+    let percentages = [fee_distribution.platform_percentage, fee_distribution.dao_treasury_percentage, fee_distribution.burn_percentage];
+    assert!(percentages.iter().all(|percentage| *percentage >= Decimal::zero()), "Fee percentages must not be negative");
+    assert_eq!(percentages.iter().fold(Decimal::zero(), |sum, percentage| sum + *percentage), dec!("100"), "Fee percentages must add up to 100");
+}
+
+// rounding leftovers go to the platform
+pub(crate) fn split(fee_distribution: &FeeDistribution, mut fees: Bucket) -> FeeShares {
+    let amount = fees.amount();
+    let dao_treasury = fees.take_advanced(amount * fee_distribution.dao_treasury_percentage / dec!("100"), WithdrawStrategy::Rounded(RoundingMode::ToZero));
+    let burn = fees.take_advanced(amount * fee_distribution.burn_percentage / dec!("100"), WithdrawStrategy::Rounded(RoundingMode::ToZero));
+
+    FeeShares { platform: fees, dao_treasury, burn }
+}
+
+pub(crate) fn get(dao_fee_records: &KeyValueStore<String, DaoFeeRecord>, dao_id: &String) -> DaoFeeRecord {
+    match dao_fee_records.get(dao_id) {
+        Some(record) => record.clone(),
+        None => DaoFeeRecord::default(),
+    }
+}
+
+pub(crate) fn record_collected(dao_fee_records: &KeyValueStore<String, DaoFeeRecord>, dao_id: &String, resource_address: ResourceAddress, amount: Decimal) {
+    let mut record = get(dao_fee_records, dao_id);
+    add(&mut record.collected, resource_address, amount);
+    dao_fee_records.insert(dao_id.clone(), record);
+}
+
+pub(crate) fn record_distributed(dao_fee_records: &KeyValueStore<String, DaoFeeRecord>, dao_id: &String, resource_address: ResourceAddress, fee_shares: &FeeShares) {
+    let mut record = get(dao_fee_records, dao_id);
+    add(&mut record.to_platform, resource_address, fee_shares.platform.amount());
+    add(&mut record.to_dao_treasury, resource_address, fee_shares.dao_treasury.amount());
+    add(&mut record.burned, resource_address, fee_shares.burn.amount());
+    dao_fee_records.insert(dao_id.clone(), record);
+}
+
+fn add(totals: &mut HashMap<ResourceAddress, Decimal>, resource_address: ResourceAddress, amount: Decimal) {
+    let total = totals.entry(resource_address).or_insert(Decimal::zero());
+    *total += amount;
+}
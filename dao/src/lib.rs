@@ -1,25 +1,78 @@
 use scrypto::prelude::*;
 
-mod dao;
-mod dao_admin_badge;
-mod proposal;
+pub mod additional_data;
+pub mod dao;
+pub mod dao_admin_badge;
+pub mod dao_application;
+pub mod dao_operation;
+pub mod dao_registry;
+pub mod dao_settings;
+pub mod events;
+pub mod fee_distribution;
+pub mod proposal;
+pub mod migration;
+mod proposal_deposit;
+pub mod proposal_fee;
 mod proposal_option;
 mod proposal_result;
 mod proposal_result_option;
+mod proposal_reward;
+mod proposal_sponsorship;
 mod proposal_vote;
 mod utils;
+mod validation;
+pub mod views;
+
+use events::*;
 
 #[blueprint]
+#[events(
+    DaoCreated,
+    DaoApplied,
+    DaoApplicationApproved,
+    DaoApplicationRejected,
+    DaoCreationPolicyChanged,
+    DaoUpdated,
+    DaoAdditionalDataUpdated,
+    DaoStatusChanged,
+    DaoSettingsUpdated,
+    AdminBadgeMinted,
+    AdminBadgeRevoked,
+    AdminBadgeRenewed,
+    AdminBadgesBurned,
+    OperationSubmitted,
+    OperationApproved,
+    OperationExecuted,
+    ProposalCreated,
+    ProposalUpdated,
+    ProposalAdditionalDataUpdated,
+    ProposalActivated,
+    ProposalSeconded,
+    ProposalCancelled,
+    OptionAdded,
+    VoteCast,
+    ResultUpdated,
+    RewardClaimed,
+    RewardSwept,
+    DepositRefunded,
+    DepositSlashed,
+    TreasuryWithdrawn,
+    FeesWithdrawn,
+    FeesDistributed,
+    FeeDistributionChanged,
+    PriceChanged,
+    FeeResourceChanged,
+    ProposalFeeOverrideChanged,
+    MaxAdminsChanged,
+    DaosMigrated,
+    ProposalsMigrated
+)]
 mod dao_hoard {
-    // the Owner Badge has to exist before compiling
-    // RESIM Martin const OWNER_BADGE: ResourceManager = resource_manager!("resource_sim1nfkwg8fa7ldhwh8exe5w4acjhp9v982svmxp3yqa8ncruad4t8fptu");
-    // RESIM Stephan const OWNER_BADGE: ResourceManager = resource_manager!("resource_sim1nfkwg8fa7ldhwh8exe5w4acjhp9v982svmxp3yqa8ncruad4t8fptu");
-    // STOKENET
-    const OWNER_BADGE: ResourceManager = resource_manager!("resource_tdx_2_1n2r7x3k0e6ed4a3cztq92gv69s4cd4fwh77me5gdw0p6h4mlh5cf82");
+    const DEFAULT_MAX_ADMINS: u32 = 10;
 
     enable_function_auth! {
-        // only the OWNER is able to instantiate our dao horde
-        dao_hoard_instantiate => rule!(require(OWNER_BADGE.address()));
+        // the owner badge is passed on instantiation, so whoever instantiates only hands control to that badge
+        dao_hoard_instantiate => rule!(allow_all);
     }
     enable_method_auth! {
         roles {
@@ -30,41 +83,135 @@ mod dao_hoard {
         methods {
             dao_hoard_set_proposal_creation_price => restrict_to: [super_admin, OWNER];
             dao_hoard_withdraw_crumb_fees => restrict_to: [super_admin, OWNER];
+            dao_hoard_set_fee_resource => restrict_to: [super_admin, OWNER];
+            dao_hoard_withdraw_fees => restrict_to: [super_admin, OWNER];
+            dao_hoard_set_dao_proposal_fee => restrict_to: [super_admin, OWNER];
+            dao_hoard_set_fee_distribution => restrict_to: [super_admin, OWNER];
+            dao_distribute_fees => PUBLIC;
+            dao_get_fee_record => PUBLIC;
+            dao_get_proposal_price => PUBLIC;
+            dao_hoard_set_default_max_admins => restrict_to: [super_admin, OWNER];
+            dao_hoard_set_dao_max_admins => restrict_to: [super_admin, OWNER];
+            dao_hoard_migrate_daos => restrict_to: [OWNER];
+            dao_hoard_migrate_proposals => restrict_to: [OWNER];
+            dao_get_admins => PUBLIC;
             dao_mint_admin_badge_by_owner => restrict_to: [super_admin, OWNER, SELF];
             dao_mint_admin_badge_by_dao_admin => restrict_to: [dao_admin];
-            dao_create => restrict_to: [dao_creator, super_admin, OWNER];
+            dao_revoke_admin_badge => restrict_to: [dao_admin];
+            dao_renew_admin_badge => restrict_to: [dao_admin];
+            dao_reissue_admin_badge => restrict_to: [dao_admin];
+            dao_revoke_admin_badge_by_vote => PUBLIC;
+            dao_burn_admin_badges => PUBLIC;
+            dao_submit_operation => restrict_to: [dao_admin];
+            dao_approve_operation => restrict_to: [dao_admin];
+            dao_create => restrict_to: [dao_creator];
+            dao_hoard_create_dao => restrict_to: [super_admin, OWNER];
+            dao_hoard_set_dao_creation_policy => restrict_to: [super_admin, OWNER];
+            dao_apply => PUBLIC;
+            dao_approve_application => restrict_to: [super_admin, OWNER];
+            dao_reject_application => restrict_to: [super_admin, OWNER];
+            dao_get_application_status => PUBLIC;
             dao_update => restrict_to: [dao_admin];
+            dao_patch => restrict_to: [dao_admin];
+            dao_update_additional_data => restrict_to: [dao_admin];
+            dao_archive => restrict_to: [dao_admin];
+            dao_reactivate => restrict_to: [dao_admin];
+            dao_freeze => restrict_to: [super_admin, OWNER];
+            dao_unfreeze => restrict_to: [super_admin, OWNER];
+            dao_set_proposal_deposit => restrict_to: [dao_admin];
+            dao_withdraw_treasury => restrict_to: [dao_admin];
+            dao_set_member_proposals => restrict_to: [dao_admin];
             dao_add_proposal => restrict_to: [dao_admin];
+            dao_add_member_proposal => PUBLIC;
             proposal_add_option => restrict_to: [dao_admin];
+            proposal_update_additional_data => restrict_to: [dao_admin];
+            proposal_update => restrict_to: [dao_admin];
+            proposal_cancel => restrict_to: [dao_admin];
+            proposal_sponsor => restrict_to: [dao_admin];
+            proposal_second => PUBLIC;
             proposal_mint_nft_vote => PUBLIC;
+            proposal_claim_reward => PUBLIC;
+            proposal_sweep_reward => restrict_to: [dao_admin];
+            proposal_refund_deposit => PUBLIC;
+            proposal_slash_deposit => PUBLIC;
+            dao_get => PUBLIC;
+            dao_list_proposals => PUBLIC;
+            dao_count => PUBLIC;
+            dao_list => PUBLIC;
+            dao_find_by_slug => PUBLIC;
+            proposal_get => PUBLIC;
+            proposal_result_get => PUBLIC;
+            proposal_has_voted => PUBLIC;
         }
     }
     struct DaoHoard {
         component_address: ComponentAddress,
+        owner_badge_address: ResourceAddress,
         owner_badge_access_rule: AccessRule,
         dao_resource_manager: ResourceManager,
         dao_admin_badges_manager: ResourceManager,
+        dao_admin_badge_ids: KeyValueStore<String, HashSet<NonFungibleLocalId>>,
+        dao_operations: KeyValueStore<u64, dao_operation::PendingDaoOperation>,
+        dao_operation_counter: u64,
         crumb_fees: Vault,
         proposal_creation_price: Decimal,
+        fee_resources: KeyValueStore<ResourceAddress, proposal_fee::FeeResource>,
+        fee_distribution: fee_distribution::FeeDistribution,
+        undistributed_fees: KeyValueStore<(String, ResourceAddress), Vault>,
+        dao_fee_records: KeyValueStore<String, fee_distribution::DaoFeeRecord>,
+        default_max_admins: u32,
+        proposal_rewards: KeyValueStore<(String, String), proposal_reward::ProposalReward>,
+        dao_settings: KeyValueStore<String, dao_settings::DaoSettings>,
+        dao_treasuries: KeyValueStore<(String, ResourceAddress), Vault>,
+        proposal_deposits: KeyValueStore<(String, String), proposal_deposit::ProposalDeposit>,
+        proposal_sponsorships: KeyValueStore<(String, String), proposal_sponsorship::ProposalSponsorship>,
+        dao_proposal_ids: KeyValueStore<String, Vec<String>>,
+        dao_registry: dao_registry::DaoRegistry,
+        dao_creation_price: Decimal,
+        dao_creation_approval_required: bool,
+        dao_applications: KeyValueStore<String, dao_application::DaoApplication>,
+        dao_extensions: KeyValueStore<String, migration::VersionedDaoExtension>,
+        proposal_extensions: KeyValueStore<(String, String), migration::VersionedProposalExtension>,
     }
 
     impl DaoHoard {
-        pub fn dao_hoard_instantiate(crumbs_token_address: ResourceAddress, proposal_creation_price: Decimal) -> Global<DaoHoard> {
+        pub fn dao_hoard_instantiate(owner_badge_address: ResourceAddress, crumbs_token_address: ResourceAddress, proposal_creation_price: Decimal, soulbound: bool) -> Global<DaoHoard> {
             let (address_reservation, component_address) = Runtime::allocate_component_address(DaoHoard::blueprint_id());
-            let owner_badge_access_rule: AccessRule = rule!(require(OWNER_BADGE.address()));
+            let owner_badge_access_rule: AccessRule = rule!(require(owner_badge_address));
             let public_rule: AccessRule = rule!(allow_all);
 
-            let dao_resource_manager = dao::create_resource_manager(OWNER_BADGE.address(), &owner_badge_access_rule, component_address);
-            let dao_admin_badges_manager = dao_admin_badge::create_dao_admin_badge_resource_manager(OWNER_BADGE.address(), &owner_badge_access_rule, component_address);
+            let dao_resource_manager = dao::create_resource_manager(owner_badge_address, &owner_badge_access_rule, component_address, soulbound);
+            let dao_admin_badges_manager = dao_admin_badge::create_dao_admin_badge_resource_manager(owner_badge_address, &owner_badge_access_rule, component_address, soulbound);
 
             let cloned_owner_badge_access_rule = owner_badge_access_rule.clone();
             let dao_hoard = Self {
                 component_address,
+                owner_badge_address,
                 owner_badge_access_rule: cloned_owner_badge_access_rule,
                 dao_resource_manager,
                 dao_admin_badges_manager,
+                dao_admin_badge_ids: KeyValueStore::new(),
+                dao_operations: KeyValueStore::new(),
+                dao_operation_counter: 0,
                 crumb_fees: Vault::new(crumbs_token_address),
                 proposal_creation_price,
+                fee_resources: KeyValueStore::new(),
+                fee_distribution: fee_distribution::FeeDistribution::default(),
+                undistributed_fees: KeyValueStore::new(),
+                dao_fee_records: KeyValueStore::new(),
+                default_max_admins: DEFAULT_MAX_ADMINS,
+                proposal_rewards: KeyValueStore::new(),
+                dao_settings: KeyValueStore::new(),
+                dao_treasuries: KeyValueStore::new(),
+                proposal_deposits: KeyValueStore::new(),
+                proposal_sponsorships: KeyValueStore::new(),
+                dao_proposal_ids: KeyValueStore::new(),
+                dao_registry: dao_registry::create(),
+                dao_creation_price: Decimal::zero(),
+                dao_creation_approval_required: false,
+                dao_applications: KeyValueStore::new(),
+                dao_extensions: KeyValueStore::new(),
+                proposal_extensions: KeyValueStore::new(),
             };
 
             let dao_hoard_global = dao_hoard.instantiate()
@@ -77,9 +224,9 @@ mod dao_hoard {
                 ))
                 .metadata(metadata!(
                     roles {
-                        metadata_setter => rule!(require_any_of(vec![global_caller(component_address), ResourceOrNonFungible::Resource(OWNER_BADGE.address())]));
+                        metadata_setter => rule!(require_any_of(vec![global_caller(component_address), ResourceOrNonFungible::Resource(owner_badge_address)]));
                         metadata_setter_updater => OWNER;
-                        metadata_locker => rule!(require_any_of(vec![global_caller(component_address), ResourceOrNonFungible::Resource(OWNER_BADGE.address())]));
+                        metadata_locker => rule!(require_any_of(vec![global_caller(component_address), ResourceOrNonFungible::Resource(owner_badge_address)]));
                         metadata_locker_updater => OWNER;
                     },
                     init {
@@ -97,19 +244,71 @@ mod dao_hoard {
                         royalty_setter_updater => OWNER;
                         royalty_locker => OWNER;
                         royalty_locker_updater => OWNER;
-                        royalty_claimer => rule!(require_any_of(vec![global_caller(component_address), ResourceOrNonFungible::Resource(OWNER_BADGE.address())]));
+                        royalty_claimer => rule!(require_any_of(vec![global_caller(component_address), ResourceOrNonFungible::Resource(owner_badge_address)]));
                         royalty_claimer_updater => OWNER;
                     },
                     init {
                         dao_hoard_set_proposal_creation_price => Free, updatable;
                         dao_hoard_withdraw_crumb_fees => Free, updatable;
+                        dao_hoard_set_fee_resource => Free, updatable;
+                        dao_hoard_withdraw_fees => Free, updatable;
+                        dao_hoard_set_dao_proposal_fee => Free, updatable;
+                        dao_hoard_set_fee_distribution => Free, updatable;
+                        dao_distribute_fees => Free, updatable;
+                        dao_get_fee_record => Free, updatable;
+                        dao_get_proposal_price => Free, updatable;
+                        dao_hoard_set_default_max_admins => Free, updatable;
+                        dao_hoard_set_dao_max_admins => Free, updatable;
+                        dao_hoard_migrate_daos => Free, updatable;
+                        dao_hoard_migrate_proposals => Free, updatable;
+                        dao_get_admins => Free, updatable;
                         dao_mint_admin_badge_by_owner => Free, updatable;
                         dao_mint_admin_badge_by_dao_admin => Usd(dec!("0.10")), updatable;
+                        dao_revoke_admin_badge => Free, updatable;
+                        dao_renew_admin_badge => Free, updatable;
+                        dao_reissue_admin_badge => Free, updatable;
+                        dao_revoke_admin_badge_by_vote => Free, updatable;
+                        dao_burn_admin_badges => Free, updatable;
+                        dao_submit_operation => Free, updatable;
+                        dao_approve_operation => Free, updatable;
                         dao_create => Free, updatable;
+                        dao_hoard_create_dao => Free, updatable;
+                        dao_hoard_set_dao_creation_policy => Free, updatable;
+                        dao_apply => Free, updatable;
+                        dao_approve_application => Free, updatable;
+                        dao_reject_application => Free, updatable;
+                        dao_get_application_status => Free, updatable;
                         dao_update => Free, updatable;
+                        dao_patch => Free, updatable;
+                        dao_update_additional_data => Free, updatable;
+                        dao_archive => Free, updatable;
+                        dao_reactivate => Free, updatable;
+                        dao_freeze => Free, updatable;
+                        dao_unfreeze => Free, updatable;
+                        dao_set_proposal_deposit => Free, updatable;
+                        dao_withdraw_treasury => Free, updatable;
+                        dao_set_member_proposals => Free, updatable;
                         dao_add_proposal => Free, updatable;
+                        dao_add_member_proposal => Free, updatable;
                         proposal_add_option => Free, updatable;
+                        proposal_update_additional_data => Free, updatable;
+                        proposal_update => Free, updatable;
+                        proposal_cancel => Free, updatable;
+                        proposal_sponsor => Free, updatable;
+                        proposal_second => Free, updatable;
                         proposal_mint_nft_vote => Usd(dec!("0.05")), updatable;
+                        proposal_claim_reward => Free, updatable;
+                        proposal_sweep_reward => Free, updatable;
+                        proposal_refund_deposit => Free, updatable;
+                        proposal_slash_deposit => Free, updatable;
+                        dao_get => Free, updatable;
+                        dao_list_proposals => Free, updatable;
+                        dao_count => Free, updatable;
+                        dao_list => Free, updatable;
+                        dao_find_by_slug => Free, updatable;
+                        proposal_get => Free, updatable;
+                        proposal_result_get => Free, updatable;
+                        proposal_has_voted => Free, updatable;
                     }
                 })
                 .globalize();
@@ -119,48 +318,211 @@ mod dao_hoard {
 
         pub fn dao_hoard_set_proposal_creation_price(&mut self, proposal_creation_price: Decimal) {
             self.proposal_creation_price = proposal_creation_price;
+            Runtime::emit_event(PriceChanged { proposal_creation_price });
         }
 
         pub fn dao_hoard_withdraw_crumb_fees(&mut self) -> Bucket {
-            self.crumb_fees.take_all()
+            let crumb_fees = self.crumb_fees.take_all();
+            Runtime::emit_event(FeesWithdrawn { resource_address: crumb_fees.resource_address(), amount: crumb_fees.amount() });
+            crumb_fees
+        }
+
+        pub fn dao_hoard_set_fee_resource(&mut self, resource_address: ResourceAddress, price: Option<Decimal>) {
+            assert_ne!(resource_address, self.crumb_fees.resource_address(), "CRUMBS price is set with dao_hoard_set_proposal_creation_price");
+            proposal_fee::set_price(&self.fee_resources, resource_address, price);
+            Runtime::emit_event(FeeResourceChanged { resource_address, price });
+        }
+
+        pub fn dao_hoard_withdraw_fees(&mut self, resource_address: ResourceAddress) -> Bucket {
+            let fees = proposal_fee::take_all(&self.fee_resources, resource_address);
+            Runtime::emit_event(FeesWithdrawn { resource_address, amount: fees.amount() });
+            fees
+        }
+
+        pub fn dao_hoard_set_dao_proposal_fee(&mut self, dao_id: String, fee_override: Option<proposal_fee::ProposalFeeOverride>) {
+            if let Some(fee_override) = &fee_override {
+                proposal_fee::check_override(fee_override);
+            }
+            let mut settings = dao_settings::get(&self.dao_settings, &dao_id);
+            settings.proposal_fee_override = fee_override;
+            self.dao_settings.insert(dao_id.clone(), settings);
+            Runtime::emit_event(ProposalFeeOverrideChanged { dao_id, fee_override });
+        }
+
+        pub fn dao_hoard_set_fee_distribution(&mut self, fee_distribution: fee_distribution::FeeDistribution) {
+            fee_distribution::check(&fee_distribution);
+            self.fee_distribution = fee_distribution;
+            Runtime::emit_event(FeeDistributionChanged { fee_distribution });
+        }
+
+        // the burn share is burned by the DaoHoard, so a fee resource must grant its component the burner role
+        pub fn dao_distribute_fees(&mut self, dao_id: String) {
+            let record = fee_distribution::get(&self.dao_fee_records, &dao_id);
+            for resource_address in record.resources() {
+                let fees = match self.undistributed_fees.get_mut(&(dao_id.clone(), resource_address)) {
+                    Some(mut vault) => vault.take_all(),
+                    None => continue,
+                };
+                if fees.is_empty() {
+                    fees.drop_empty();
+                    continue;
+                }
+
+                let fee_shares = fee_distribution::split(&self.fee_distribution, fees);
+                fee_distribution::record_distributed(&self.dao_fee_records, &dao_id, resource_address, &fee_shares);
+                Runtime::emit_event(FeesDistributed {
+                    dao_id: dao_id.clone(),
+                    resource_address,
+                    platform_amount: fee_shares.platform.amount(),
+                    dao_treasury_amount: fee_shares.dao_treasury.amount(),
+                    burned_amount: fee_shares.burn.amount(),
+                });
+
+                self.put_into_platform_fees(fee_shares.platform);
+                if fee_shares.dao_treasury.is_empty() {
+                    fee_shares.dao_treasury.drop_empty();
+                } else {
+                    self.put_into_dao_treasury(&dao_id, fee_shares.dao_treasury);
+                }
+                if fee_shares.burn.is_empty() {
+                    fee_shares.burn.drop_empty();
+                } else {
+                    fee_shares.burn.burn();
+                }
+            }
+        }
+
+        pub fn dao_get_fee_record(&self, dao_id: String) -> fee_distribution::DaoFeeRecord {
+            fee_distribution::get(&self.dao_fee_records, &dao_id)
+        }
+
+        pub fn dao_get_proposal_price(&self, dao_id: String, resource_address: ResourceAddress) -> Decimal {
+            self.proposal_price(&dao_id, resource_address)
+        }
+
+        pub fn dao_hoard_set_default_max_admins(&mut self, default_max_admins: u32) {
+            self.default_max_admins = default_max_admins;
+            Runtime::emit_event(MaxAdminsChanged { dao_id: None, max_admins: Some(default_max_admins) });
+        }
+
+        pub fn dao_hoard_set_dao_max_admins(&mut self, dao_id: String, max_admins: Option<u32>) {
+            let mut settings = dao_settings::get(&self.dao_settings, &dao_id);
+            settings.max_admins = max_admins;
+            self.dao_settings.insert(dao_id.clone(), settings);
+            Runtime::emit_event(MaxAdminsChanged { dao_id: Some(dao_id), max_admins });
+        }
+
+        // migrates DAOs in registry order, call with increasing offsets until dao_count is reached
+        pub fn dao_hoard_migrate_daos(&mut self, offset: u64, limit: u64) -> Vec<String> {
+            let dao_ids: Vec<String> = dao_registry::dao_ids(&self.dao_registry, offset, limit)
+                .into_iter()
+                .filter(|dao_id| migration::migrate_dao(self.dao_resource_manager, &self.dao_extensions, dao_id))
+                .collect();
+            Runtime::emit_event(DaosMigrated { dao_ids: dao_ids.clone() });
+            dao_ids
+        }
+
+        pub fn dao_hoard_migrate_proposals(&mut self, dao_id: String, offset: u64, limit: u64) -> Vec<String> {
+            let proposal_resource_manager = dao::get(self.dao_resource_manager, &dao_id).proposals();
+            let proposal_ids: Vec<String> = self.dao_list_proposals(dao_id.clone(), offset, limit)
+                .into_iter()
+                .filter(|proposal_id| migration::migrate_proposal(proposal_resource_manager, &self.proposal_extensions, &dao_id, proposal_id))
+                .collect();
+            Runtime::emit_event(ProposalsMigrated { dao_id, proposal_ids: proposal_ids.clone() });
+            proposal_ids
+        }
+
+        pub fn dao_get_admins(&self, dao_id: String) -> Vec<dao_admin_badge::DaoAdminBadgeView> {
+            dao_admin_badge::list(self.dao_admin_badges_manager, &self.dao_admin_badge_ids, &dao_id)
         }
 
-        pub fn dao_mint_admin_badge_by_owner(&mut self, dao_id: String) -> Bucket {
+        pub fn dao_mint_admin_badge_by_owner(&mut self, dao_id: String, role: dao_admin_badge::DaoAdminRole, expires_epoch: Option<u64>) -> Bucket {
             let dao = dao::get(self.dao_resource_manager, &dao_id);
-            let dao_admin_badge_bucket = dao_admin_badge::mint(self.dao_admin_badges_manager, dao_id, dao.name());
+            let max_admins = self.max_admins(&dao_id);
+            let dao_admin_badge_bucket = dao_admin_badge::mint(self.dao_admin_badges_manager, &self.dao_admin_badge_ids, dao_id.clone(), dao.name(), dao.key_image_url(), role, expires_epoch, max_admins);
+            emit_admin_badge_minted(dao_id, &dao_admin_badge_bucket, role);
             dao_admin_badge_bucket
         }
 
-        pub fn dao_mint_admin_badge_by_dao_admin(&mut self, admin_badge: Proof) -> Bucket {
+        pub fn dao_mint_admin_badge_by_dao_admin(&mut self, admin_badge: Proof, role: dao_admin_badge::DaoAdminRole, expires_epoch: Option<u64>) -> Bucket {
             let admin_badge_nft: NonFungible<dao_admin_badge::DaoAdminBadge> =
                 admin_badge.check(self.dao_admin_badges_manager.address()).as_non_fungible().non_fungible();
 
-            let dao = dao::get(self.dao_resource_manager, &admin_badge_nft.data().dao_id());
-            let dao_admin_badge_bucket = dao_admin_badge::mint(self.dao_admin_badges_manager, admin_badge_nft.data().dao_id(), dao.name());
-            dao_admin_badge_bucket
+            assert!(dao_admin_badge::is_active(&self.dao_admin_badge_ids, &admin_badge_nft.data().dao_id(), admin_badge_nft.local_id()), "Admin badge has been revoked");
+            assert!(!admin_badge_nft.data().is_expired(), "Admin badge has expired");
+            assert!(admin_badge_nft.data().role().has_permission(dao_admin_badge::DaoPermission::ManageAdminBadges), "Only DAO owners can mint admin badges");
+            self.check_no_approval_required(&admin_badge_nft.data().dao_id());
+
+            let dao_admin_badge_bucket = self.execute_operation(&admin_badge_nft.data().dao_id(), dao_operation::DaoOperation::MintAdminBadge { role, expires_epoch });
+            dao_admin_badge_bucket.unwrap()
+        }
+
+        pub fn dao_revoke_admin_badge(&mut self, admin_badges: Proof, dao_id: String, badge_id: NonFungibleLocalId, holder_vault: InternalAddress) {
+            dao_admin_badge::check_is_dao_admin(self.dao_admin_badges_manager, &self.dao_admin_badge_ids, admin_badges, &dao_id, dao_admin_badge::DaoPermission::ManageAdminBadges);
+            self.check_no_approval_required(&dao_id);
+
+            self.execute_operation(&dao_id, dao_operation::DaoOperation::RevokeAdminBadge { badge_id, holder_vault });
+        }
+
+        pub fn dao_renew_admin_badge(&mut self, admin_badges: Proof, dao_id: String, badge_id: NonFungibleLocalId, expires_epoch: Option<u64>) {
+            dao_admin_badge::check_is_dao_admin(self.dao_admin_badges_manager, &self.dao_admin_badge_ids, admin_badges, &dao_id, dao_admin_badge::DaoPermission::ManageAdminBadges);
+            self.check_no_approval_required(&dao_id);
+
+            self.execute_operation(&dao_id, dao_operation::DaoOperation::RenewAdminBadge { badge_id, expires_epoch });
+        }
+
+        pub fn dao_reissue_admin_badge(&mut self, admin_badges: Proof, dao_id: String, badge_id: NonFungibleLocalId, holder_vault: InternalAddress) -> Bucket {
+            dao_admin_badge::check_is_dao_admin(self.dao_admin_badges_manager, &self.dao_admin_badge_ids, admin_badges, &dao_id, dao_admin_badge::DaoPermission::ManageAdminBadges);
+            self.check_no_approval_required(&dao_id);
+
+            let dao_admin_badge_bucket = self.execute_operation(&dao_id, dao_operation::DaoOperation::ReissueAdminBadge { badge_id, holder_vault });
+            dao_admin_badge_bucket.unwrap()
+        }
+
+        pub fn dao_revoke_admin_badge_by_vote(&mut self, dao_id: String, proposal_id: String, holder_vault: InternalAddress) {
+            let dao = dao::get(self.dao_resource_manager, &dao_id);
+            dao.check_not_frozen();
+            let proposal = proposal::get(dao.proposals(), &proposal_id);
+
+            let badge_id = proposal.additional_data_value(proposal::REVOKE_ADMIN_BADGE_KEY)
+                .unwrap_or_else(|| panic!("Proposal {} is no admin badge revocation", proposal_id));
+            let settings = dao_settings::get(&self.dao_settings, &dao_id);
+            assert!(settings.quorum > Decimal::zero(), "DAO {} has no quorum, admin badges can not be revoked by vote", dao_id);
+            assert!(proposal.is_approved(settings.quorum), "Proposal {} has not been approved", proposal_id);
+
+            let badge_id = NonFungibleLocalId::from_str(&badge_id)
+                .unwrap_or_else(|_| panic!("Invalid admin badge id {}", badge_id));
+            dao_admin_badge::revoke(self.dao_admin_badges_manager, &self.dao_admin_badge_ids, &dao_id, &badge_id, holder_vault, settings.approval_threshold);
+            Runtime::emit_event(AdminBadgeRevoked { dao_id, badge_id });
+        }
+
+        pub fn dao_burn_admin_badges(&mut self, admin_badges: Bucket) {
+            let badge_ids = admin_badges.as_non_fungible().non_fungible_local_ids().into_iter().collect();
+            dao_admin_badge::burn(self.dao_admin_badges_manager, &self.dao_admin_badge_ids, admin_badges, |dao_id| dao_settings::get(&self.dao_settings, dao_id).approval_threshold);
+            Runtime::emit_event(AdminBadgesBurned { badge_ids });
         }
 
         pub fn dao_create(
             &mut self,
+            mut payment: Bucket,
             dao_id: String,
             name: String,
             info_url: String,
             key_image_url: String,
-            dao_type: String,
+            dao_type: dao::DaoType,
             governance_resource: ResourceAddress,
             about: String,
             general: String,
             created: String,
             rules: Vec<String>,
             additional_data: HashMap<String, String>,
-        ) -> (Bucket, Bucket) {
+        ) -> (Bucket, Bucket, Bucket) {
+            assert!(!self.dao_creation_approval_required, "DAO creation requires approval, apply with dao_apply");
 
-            let dao_bucket = dao::create(
-                OWNER_BADGE.address(),
-                &self.owner_badge_access_rule,
-                self.component_address,
-                self.dao_resource_manager,
-                dao_id.clone(),
+            let dao_creation_fee = self.take_dao_creation_fee(&mut payment);
+            self.crumb_fees.put(dao_creation_fee);
+
+            let (dao_bucket, admin_badge_bucket) = self.create_dao(dao_id, dao_application::DaoApplicationData {
                 name,
                 info_url,
                 key_image_url,
@@ -171,90 +533,392 @@ mod dao_hoard {
                 created,
                 rules,
                 additional_data,
-            );
+            });
+            (dao_bucket, admin_badge_bucket, payment)
+        }
 
-            let admin_badge_bucket = self.dao_mint_admin_badge_by_owner(dao_id);
-            (dao_bucket, admin_badge_bucket)
+        // the platform creates DAOs without fee and approval
+        pub fn dao_hoard_create_dao(
+            &mut self,
+            dao_id: String,
+            name: String,
+            info_url: String,
+            key_image_url: String,
+            dao_type: dao::DaoType,
+            governance_resource: ResourceAddress,
+            about: String,
+            general: String,
+            created: String,
+            rules: Vec<String>,
+            additional_data: HashMap<String, String>,
+        ) -> (Bucket, Bucket) {
+            self.create_dao(dao_id, dao_application::DaoApplicationData {
+                name,
+                info_url,
+                key_image_url,
+                dao_type,
+                governance_resource,
+                about,
+                general,
+                created,
+                rules,
+                additional_data,
+            })
         }
 
-        pub fn dao_update(
-            &self,
-            admin_badges: Proof,
+        pub fn dao_hoard_set_dao_creation_policy(&mut self, dao_creation_price: Decimal, approval_required: bool) {
+            assert!(dao_creation_price >= Decimal::zero(), "DAO creation price must not be negative");
+            self.dao_creation_price = dao_creation_price;
+            self.dao_creation_approval_required = approval_required;
+            Runtime::emit_event(DaoCreationPolicyChanged { dao_creation_price, approval_required });
+        }
+
+        // the DAO NFT and owner badge are deposited into account once the application is approved
+        pub fn dao_apply(
+            &mut self,
+            mut payment: Bucket,
+            account: Global<Account>,
             dao_id: String,
             name: String,
             info_url: String,
             key_image_url: String,
-            dao_type: String,
+            dao_type: dao::DaoType,
             governance_resource: ResourceAddress,
             about: String,
             general: String,
+            created: String,
             rules: Vec<String>,
             additional_data: HashMap<String, String>,
-        ) {
-            dao_admin_badge::check_is_dao_admin(self.dao_admin_badges_manager, admin_badges, &dao_id);
+        ) -> Bucket {
+            assert!(self.dao_applications.get(&dao_id).is_none(), "Application for DAO {} already exists", dao_id);
+            dao_registry::check_available(&self.dao_registry, &dao_id, &name);
 
-            dao::update(
-                self.dao_resource_manager,
-                dao_id,
-                name,
+            let data = dao_application::DaoApplicationData {
+                name: name.clone(),
                 info_url,
                 key_image_url,
                 dao_type,
                 governance_resource,
                 about,
                 general,
+                created,
                 rules,
                 additional_data,
-            );
+            };
+            dao_application::check(&dao_id, &data);
+
+            let dao_creation_fee = self.take_dao_creation_fee(&mut payment);
+            let application = dao_application::submit(data, account, dao_creation_fee);
+            self.dao_applications.insert(dao_id.clone(), application);
+            Runtime::emit_event(DaoApplied { dao_id, name });
+
+            payment
+        }
+
+        // an account refusing the deposits aborts the approval, the application stays pending
+        pub fn dao_approve_application(&mut self, dao_id: String) {
+            let (data, account_address, dao_creation_fee) = {
+                let mut application = self.dao_applications.get_mut(&dao_id)
+                    .unwrap_or_else(|| panic!("Application for DAO {} does not exist", dao_id));
+                dao_application::approve(&mut application, &dao_id)
+            };
+            self.crumb_fees.put(dao_creation_fee);
+            Runtime::emit_event(DaoApplicationApproved { dao_id: dao_id.clone() });
+
+            let (dao_bucket, admin_badge_bucket) = self.create_dao(dao_id, data);
+            let mut account: Global<Account> = Global::from(account_address);
+            account.try_deposit_or_abort(dao_bucket, None);
+            account.try_deposit_or_abort(admin_badge_bucket, None);
+        }
+
+        pub fn dao_reject_application(&mut self, dao_id: String) {
+            let (account_address, dao_creation_fee) = {
+                let mut application = self.dao_applications.get_mut(&dao_id)
+                    .unwrap_or_else(|| panic!("Application for DAO {} does not exist", dao_id));
+                dao_application::reject(&mut application, &dao_id)
+            };
+            Runtime::emit_event(DaoApplicationRejected { dao_id });
+
+            if dao_creation_fee.is_empty() {
+                dao_creation_fee.drop_empty();
+            } else {
+                let mut account: Global<Account> = Global::from(account_address);
+                account.try_deposit_or_abort(dao_creation_fee, None);
+            }
+        }
+
+        pub fn dao_get_application_status(&self, dao_id: String) -> Option<dao_application::DaoApplicationStatus> {
+            self.dao_applications.get(&dao_id).map(|application| application.status())
+        }
+
+        pub fn dao_update(
+            &mut self,
+            admin_badges: Proof,
+            dao_id: String,
+            name: String,
+            info_url: String,
+            key_image_url: String,
+            dao_type: dao::DaoType,
+            governance_resource: ResourceAddress,
+            about: String,
+            general: String,
+            rules: Vec<String>,
+            additional_data: HashMap<String, String>,
+        ) {
+            dao_admin_badge::check_is_dao_admin(self.dao_admin_badges_manager, &self.dao_admin_badge_ids, admin_badges, &dao_id, dao_admin_badge::DaoPermission::UpdateDao);
+            self.check_no_approval_required(&dao_id);
+
+            self.execute_operation(&dao_id, dao_operation::DaoOperation::UpdateDao {
+                patch: dao::DaoPatch {
+                    name: Some(name),
+                    info_url: Some(info_url),
+                    key_image_url: Some(key_image_url),
+                    dao_type: Some(dao_type),
+                    governance_resource: Some(governance_resource),
+                    about: Some(about),
+                    general: Some(general),
+                    rules: Some(rules),
+                    additional_data: Some(additional_data),
+                },
+            });
+        }
+
+        pub fn dao_patch(&mut self, admin_badges: Proof, dao_id: String, patch: dao::DaoPatch) {
+            dao_admin_badge::check_is_dao_admin(self.dao_admin_badges_manager, &self.dao_admin_badge_ids, admin_badges, &dao_id, dao_admin_badge::DaoPermission::UpdateDao);
+            self.check_no_approval_required(&dao_id);
+
+            self.execute_operation(&dao_id, dao_operation::DaoOperation::UpdateDao { patch });
+        }
+
+        pub fn dao_update_additional_data(&mut self, admin_badges: Proof, dao_id: String, changes: Vec<additional_data::AdditionalDataChange>) {
+            dao_admin_badge::check_is_dao_admin(self.dao_admin_badges_manager, &self.dao_admin_badge_ids, admin_badges, &dao_id, dao_admin_badge::DaoPermission::UpdateDao);
+            self.check_no_approval_required(&dao_id);
+
+            self.execute_operation(&dao_id, dao_operation::DaoOperation::UpdateAdditionalData { changes });
+        }
+
+        pub fn dao_archive(&mut self, admin_badges: Proof, dao_id: String) {
+            dao_admin_badge::check_is_dao_admin(self.dao_admin_badges_manager, &self.dao_admin_badge_ids, admin_badges, &dao_id, dao_admin_badge::DaoPermission::UpdateDao);
+            self.check_no_approval_required(&dao_id);
+
+            self.execute_operation(&dao_id, dao_operation::DaoOperation::SetStatus { status: dao::DaoStatus::Archived });
+        }
+
+        pub fn dao_reactivate(&mut self, admin_badges: Proof, dao_id: String) {
+            dao_admin_badge::check_is_dao_admin(self.dao_admin_badges_manager, &self.dao_admin_badge_ids, admin_badges, &dao_id, dao_admin_badge::DaoPermission::UpdateDao);
+            self.check_no_approval_required(&dao_id);
+
+            self.execute_operation(&dao_id, dao_operation::DaoOperation::SetStatus { status: dao::DaoStatus::Active });
+        }
+
+        pub fn dao_freeze(&mut self, dao_id: String) {
+            assert!(!dao::get(self.dao_resource_manager, &dao_id).is_frozen(), "DAO {} is already frozen", dao_id);
+            self.set_dao_frozen(&dao_id, true);
+        }
+
+        pub fn dao_unfreeze(&mut self, dao_id: String) {
+            assert!(dao::get(self.dao_resource_manager, &dao_id).is_frozen(), "DAO {} is not frozen", dao_id);
+            self.set_dao_frozen(&dao_id, false);
+        }
+
+        pub fn dao_submit_operation(
+            &mut self,
+            admin_badges: Proof,
+            dao_id: String,
+            operation: dao_operation::DaoOperation,
+        ) -> (u64, Option<Bucket>) {
+            let admin_badge_id = dao_admin_badge::check_is_single_dao_admin(self.dao_admin_badges_manager, &self.dao_admin_badge_ids, admin_badges, &dao_id, operation.permission());
+            dao::get(self.dao_resource_manager, &dao_id).check_not_frozen();
+
+            let settings = dao_settings::get(&self.dao_settings, &dao_id);
+            // a single admin mints through dao_mint_admin_badge_by_dao_admin, which carries the minting royalty
+            if let dao_operation::DaoOperation::MintAdminBadge { .. } = operation {
+                assert!(settings.approval_threshold > 1, "DAO {} requires no approvals, mint admin badges with dao_mint_admin_badge_by_dao_admin", dao_id);
+            }
+            let operation_id = self.dao_operation_counter;
+            self.dao_operation_counter += 1;
+            self.dao_operations.insert(operation_id, dao_operation::submit(dao_id.clone(), operation, settings.approval_expiry_epochs, admin_badge_id));
+            Runtime::emit_event(OperationSubmitted { dao_id: dao_id.clone(), operation_id });
+
+            let admin_badge_bucket = self.execute_operation_if_approved(&dao_id, operation_id);
+            (operation_id, admin_badge_bucket)
+        }
+
+        pub fn dao_approve_operation(
+            &mut self,
+            admin_badges: Proof,
+            dao_id: String,
+            operation_id: u64,
+        ) -> Option<Bucket> {
+            let permission = self.dao_operations.get(&operation_id)
+                .unwrap_or_else(|| panic!("Operation {} does not exist", operation_id))
+                .operation()
+                .permission();
+            let admin_badge_id = dao_admin_badge::check_is_single_dao_admin(self.dao_admin_badges_manager, &self.dao_admin_badge_ids, admin_badges, &dao_id, permission);
+
+            {
+                let mut pending_dao_operation = self.dao_operations.get_mut(&operation_id).unwrap();
+                dao_operation::approve(&mut pending_dao_operation, &dao_id, admin_badge_id);
+            }
+            Runtime::emit_event(OperationApproved { dao_id: dao_id.clone(), operation_id });
+            self.execute_operation_if_approved(&dao_id, operation_id)
+        }
+
+        pub fn dao_set_proposal_deposit(
+            &mut self,
+            admin_badges: Proof,
+            dao_id: String,
+            proposal_deposit: Decimal,
+            quorum: Decimal,
+            deposit_slash_target: dao_settings::DepositSlashTarget,
+        ) {
+            dao_admin_badge::check_is_dao_admin(self.dao_admin_badges_manager, &self.dao_admin_badge_ids, admin_badges, &dao_id, dao_admin_badge::DaoPermission::UpdateDao);
+            self.check_no_approval_required(&dao_id);
+
+            self.execute_operation(&dao_id, dao_operation::DaoOperation::SetProposalDeposit { proposal_deposit, quorum, deposit_slash_target });
+        }
+
+        pub fn dao_withdraw_treasury(&mut self, admin_badges: Proof, dao_id: String, resource_address: ResourceAddress) -> Bucket {
+            dao_admin_badge::check_is_dao_admin(self.dao_admin_badges_manager, &self.dao_admin_badge_ids, admin_badges, &dao_id, dao_admin_badge::DaoPermission::ManageTreasury);
+            self.check_no_approval_required(&dao_id);
+
+            let treasury_bucket = self.execute_operation(&dao_id, dao_operation::DaoOperation::WithdrawTreasury { resource_address });
+            treasury_bucket.unwrap()
+        }
+
+        pub fn dao_set_member_proposals(
+            &mut self,
+            admin_badges: Proof,
+            dao_id: String,
+            member_proposal_threshold: Option<Decimal>,
+            member_proposal_sponsorship: bool,
+            member_proposal_seconds: u32,
+        ) {
+            dao_admin_badge::check_is_dao_admin(self.dao_admin_badges_manager, &self.dao_admin_badge_ids, admin_badges, &dao_id, dao_admin_badge::DaoPermission::UpdateDao);
+            self.check_no_approval_required(&dao_id);
+
+            self.execute_operation(&dao_id, dao_operation::DaoOperation::SetMemberProposals {
+                member_proposal_threshold,
+                member_proposal_sponsorship,
+                member_proposal_seconds,
+            });
         }
 
         pub fn dao_add_proposal(
             &mut self,
             admin_badges: Proof,
-            mut payment: Bucket,
+            payment: Bucket,
             dao_id: String,
             proposal_id: String,
             title: String,
             proposal_abstract: String,
             specification: String,
             voting_start: String,
-            voting_start_epoch: u64,
+            voting_start_epoch: Option<u64>,
             voting_end: String,
-            voting_end_epoch: u64,
+            voting_end_epoch: Option<u64>,
+            voting_times: Option<proposal::VotingTimes>,
             created: String,
             info_url: String,
+            key_image_url: Option<String>,
             additional_data: HashMap<String, String>,
+            reward: Option<Bucket>,
         ) -> (Bucket, Bucket) {
-            dao_admin_badge::check_is_dao_admin(self.dao_admin_badges_manager, admin_badges, &dao_id);
+            // only DAO owners can put an admin badge up for revocation
+            let permission = if additional_data.contains_key(proposal::REVOKE_ADMIN_BADGE_KEY) {
+                dao_admin_badge::DaoPermission::ManageAdminBadges
+            } else {
+                dao_admin_badge::DaoPermission::CreateProposals
+            };
+            let badge_ids = dao_admin_badge::check_is_dao_admin(self.dao_admin_badges_manager, &self.dao_admin_badge_ids, admin_badges, &dao_id, permission);
+            let proposer = Proposer::Admin { badge_id: badge_ids[0].clone() };
 
-            let crumb_fee = payment.take(self.proposal_creation_price);
-            self.crumb_fees.put(crumb_fee);
+            self.add_proposal(
+                payment,
+                dao_id,
+                proposal_id,
+                title,
+                proposal_abstract,
+                specification,
+                voting_start,
+                voting_start_epoch,
+                voting_end,
+                voting_end_epoch,
+                voting_times,
+                created,
+                info_url,
+                key_image_url,
+                additional_data,
+                reward,
+                proposal::ProposalStatus::Active,
+                proposer,
+            )
+        }
 
-            let proposal_non_fungible_id = utils::create_non_fungible_id_of_uuid(&proposal_id);
+        pub fn dao_add_member_proposal(
+            &mut self,
+            governance_proof: Proof,
+            payment: Bucket,
+            dao_id: String,
+            proposal_id: String,
+            title: String,
+            proposal_abstract: String,
+            specification: String,
+            voting_start: String,
+            voting_start_epoch: Option<u64>,
+            voting_end: String,
+            voting_end_epoch: Option<u64>,
+            voting_times: Option<proposal::VotingTimes>,
+            created: String,
+            info_url: String,
+            key_image_url: Option<String>,
+            additional_data: HashMap<String, String>,
+            reward: Option<Bucket>,
+        ) -> (Bucket, Bucket) {
+            assert!(!additional_data.contains_key(proposal::REVOKE_ADMIN_BADGE_KEY), "Only DAO owners can propose to revoke an admin badge");
+            let settings = dao_settings::get(&self.dao_settings, &dao_id);
+            let threshold = settings.member_proposal_threshold
+                .unwrap_or_else(|| panic!("DAO {} does not accept member proposals", dao_id));
 
             let dao = dao::get(self.dao_resource_manager, &dao_id);
-            let proposal = proposal::create(
+            let proposer_proof = proposal_sponsorship::check_governance_holder(governance_proof, dao.governance_resource(), threshold);
+
+            let status = if settings.member_proposal_sponsorship {
+                let key = (dao_id.clone(), proposal_id.clone());
+                assert!(self.proposal_sponsorships.get(&key).is_none(), "Sponsorship for proposal {} already exists", proposal_id);
+                let sponsorship = proposal_sponsorship::open(settings.member_proposal_seconds, &proposer_proof);
+                self.proposal_sponsorships.insert(key, sponsorship);
+                proposal::ProposalStatus::PendingSponsorship
+            } else {
+                proposal::ProposalStatus::Active
+            };
+            let governance_nfts = if proposer_proof.resource_address().is_fungible() {
+                Vec::new()
+            } else {
+                proposer_proof.as_non_fungible().non_fungible_local_ids().into_iter().collect()
+            };
+            let proposer = Proposer::Member { governance_nfts, governance_amount: proposer_proof.amount() };
+
+            self.add_proposal(
+                payment,
+                dao_id,
                 proposal_id,
                 title,
                 proposal_abstract,
                 specification,
-                dao.dao_type(),
-                dao.governance_resource(),
                 voting_start,
                 voting_start_epoch,
                 voting_end,
                 voting_end_epoch,
+                voting_times,
                 created,
                 info_url,
-                dao.key_image_url(),
+                key_image_url,
                 additional_data,
-            );
-
-            let proposal_bucket = dao
-                .proposals()
-                .mint_non_fungible(&proposal_non_fungible_id, proposal);
-
-            (proposal_bucket, payment)
+                reward,
+                status,
+                proposer,
+            )
         }
 
         pub fn proposal_add_option(
@@ -267,10 +931,130 @@ mod dao_hoard {
             option: String,
             additional_data: HashMap<String, String>,
         ) {
-            dao_admin_badge::check_is_dao_admin(self.dao_admin_badges_manager, admin_badges, &dao_id);
+            dao_admin_badge::check_is_dao_admin(self.dao_admin_badges_manager, &self.dao_admin_badge_ids, admin_badges, &dao_id, dao_admin_badge::DaoPermission::AddOptions);
+            validation::check_option(&proposal_id, &proposal_option_id, &option, &additional_data);
+
+            let dao = dao::get(self.dao_resource_manager, &dao_id);
+            dao.check_active();
+            proposal::add_option(dao.proposals(), proposal_id.clone(), proposal_option_id.clone(), rank, option, additional_data);
+            Runtime::emit_event(OptionAdded { dao_id, proposal_id, proposal_option_id, rank });
+        }
+
+        pub fn proposal_update_additional_data(
+            &mut self,
+            admin_badges: Proof,
+            dao_id: String,
+            proposal_id: String,
+            changes: Vec<additional_data::AdditionalDataChange>,
+        ) {
+            dao_admin_badge::check_is_dao_admin(self.dao_admin_badges_manager, &self.dao_admin_badge_ids, admin_badges, &dao_id, dao_admin_badge::DaoPermission::CreateProposals);
 
             let dao = dao::get(self.dao_resource_manager, &dao_id);
-            proposal::add_option(dao.proposals(), proposal_id, proposal_option_id, rank, option, additional_data);
+            dao.check_active();
+            let proposal = proposal::get(dao.proposals(), &proposal_id);
+            proposal.check_editable();
+            let mut proposal_additional_data = proposal.additional_data();
+            let mut proposal_additional_data_vec = proposal.additional_data_vec();
+            additional_data::apply(&mut proposal_additional_data, &mut proposal_additional_data_vec, changes.clone());
+            proposal.check_revoked_badge_unchanged(&proposal_additional_data);
+
+            proposal::set_additional_data(dao.proposals(), &proposal_id, proposal_additional_data, proposal_additional_data_vec);
+            Runtime::emit_event(ProposalAdditionalDataUpdated { dao_id, proposal_id, changes });
+        }
+
+        pub fn proposal_update(
+            &mut self,
+            admin_badges: Proof,
+            dao_id: String,
+            proposal_id: String,
+            info_url: Option<String>,
+            key_image_url: Option<String>,
+            additional_data: Option<HashMap<String, String>>,
+        ) {
+            dao_admin_badge::check_is_dao_admin(self.dao_admin_badges_manager, &self.dao_admin_badge_ids, admin_badges, &dao_id, dao_admin_badge::DaoPermission::CreateProposals);
+            if let Some(info_url) = &info_url {
+                validation::check_url("Info URL", info_url);
+            }
+            if let Some(key_image_url) = &key_image_url {
+                validation::check_url("Key image URL", key_image_url);
+            }
+            if let Some(additional_data) = &additional_data {
+                validation::check_additional_data(additional_data);
+            }
+
+            let dao = dao::get(self.dao_resource_manager, &dao_id);
+            dao.check_active();
+            let proposal = proposal::get(dao.proposals(), &proposal_id);
+            proposal.check_editable();
+            if let Some(additional_data) = &additional_data {
+                proposal.check_revoked_badge_unchanged(additional_data);
+            }
+
+            let info_url = info_url.map(Url::of);
+            let key_image_url = key_image_url.map(Url::of);
+            proposal::update_metadata(dao.proposals(), &proposal_id, info_url.clone(), key_image_url.clone(), additional_data.clone());
+            Runtime::emit_event(ProposalUpdated { dao_id, proposal_id, info_url, key_image_url, additional_data });
+        }
+
+        pub fn proposal_cancel(
+            &mut self,
+            admin_badges: Proof,
+            dao_id: String,
+            proposal_id: String,
+        ) {
+            dao_admin_badge::check_is_dao_admin(self.dao_admin_badges_manager, &self.dao_admin_badge_ids, admin_badges, &dao_id, dao_admin_badge::DaoPermission::Moderate);
+
+            let dao = dao::get(self.dao_resource_manager, &dao_id);
+            dao.check_not_frozen();
+            proposal::cancel(dao.proposals(), &proposal_id);
+            Runtime::emit_event(ProposalCancelled { dao_id: dao_id.clone(), proposal_id: proposal_id.clone() });
+
+            let proposal = proposal::get(dao.proposals(), &proposal_id);
+            let slashed_deposit = self.proposal_deposits.get_mut(&(dao_id.clone(), proposal_id.clone()))
+                .map(|mut deposit| proposal_deposit::slash(&mut deposit, &proposal));
+            if let Some((deposit, slash_target)) = slashed_deposit {
+                self.put_slashed_deposit(&dao_id, &proposal_id, deposit, slash_target);
+            }
+        }
+
+        pub fn proposal_sponsor(
+            &mut self,
+            admin_badges: Proof,
+            dao_id: String,
+            proposal_id: String,
+        ) {
+            dao_admin_badge::check_is_dao_admin(self.dao_admin_badges_manager, &self.dao_admin_badge_ids, admin_badges, &dao_id, dao_admin_badge::DaoPermission::Moderate);
+
+            let dao = dao::get(self.dao_resource_manager, &dao_id);
+            dao.check_active();
+            proposal::activate(dao.proposals(), &proposal_id);
+            Runtime::emit_event(ProposalActivated { dao_id, proposal_id });
+        }
+
+        pub fn proposal_second(
+            &mut self,
+            governance_proof: Proof,
+            dao_id: String,
+            proposal_id: String,
+        ) {
+            let settings = dao_settings::get(&self.dao_settings, &dao_id);
+            let threshold = settings.member_proposal_threshold
+                .unwrap_or_else(|| panic!("DAO {} does not accept member proposals", dao_id));
+
+            let dao = dao::get(self.dao_resource_manager, &dao_id);
+            dao.check_active();
+            let member_proof = proposal_sponsorship::check_governance_holder(governance_proof, dao.governance_resource(), threshold);
+
+            let sponsored = {
+                let mut sponsorship = self.proposal_sponsorships.get_mut(&(dao_id.clone(), proposal_id.clone()))
+                    .unwrap_or_else(|| panic!("Proposal {} is not open for sponsorship", proposal_id));
+                proposal_sponsorship::second(&mut sponsorship, member_proof)
+            };
+            Runtime::emit_event(ProposalSeconded { dao_id: dao_id.clone(), proposal_id: proposal_id.clone() });
+            if sponsored {
+                proposal::activate(dao.proposals(), &proposal_id);
+                Runtime::emit_event(ProposalActivated { dao_id, proposal_id });
+            }
         }
 
         pub fn proposal_mint_nft_vote(
@@ -284,17 +1068,504 @@ mod dao_hoard {
             created: String,
             additional_data: HashMap<String, String>,
         ) {
+            validation::check_vote(&proposal_id, &proposal_vote_id, &proposal_option_id, &created, &additional_data);
+
             let dao = dao::get(self.dao_resource_manager, &dao_id);
-            proposal::mint_nft_vote(
+            dao.check_active();
+            dao.dao_type().check_nft_votes(dao.governance_resource());
+            let power = proposal::mint_nft_vote(
                 dao.proposals(),
-                proposal_id,
+                proposal_id.clone(),
+                proposal_vote_id.clone(),
+                proposal_option_id.clone(),
+                entity,
+                voting_nfts,
+                created,
+                additional_data,
+            );
+            Runtime::emit_event(VoteCast {
+                dao_id: dao_id.clone(),
+                proposal_id: proposal_id.clone(),
                 proposal_vote_id,
                 proposal_option_id,
                 entity,
-                voting_nfts,
+                power,
+            });
+
+            let proposal = proposal::get(dao.proposals(), &proposal_id);
+            if let Some(result) = proposal.result() {
+                Runtime::emit_event(ResultUpdated {
+                    dao_id,
+                    proposal_id,
+                    vote_count: result.vote_count(),
+                    vote_power: result.vote_power(),
+                });
+            }
+        }
+
+        pub fn proposal_claim_reward(
+            &mut self,
+            dao_id: String,
+            proposal_id: String,
+            proposal_vote_id: String,
+            voting_nfts: Proof,
+        ) -> Bucket {
+            let dao = dao::get(self.dao_resource_manager, &dao_id);
+            let proposal = proposal::get(dao.proposals(), &proposal_id);
+
+            let reward_bucket = {
+                let mut proposal_reward = self.proposal_rewards.get_mut(&(dao_id.clone(), proposal_id.clone()))
+                    .unwrap_or_else(|| panic!("Proposal {} has no reward pool", proposal_id));
+                proposal_reward::claim(&mut proposal_reward, &proposal, &proposal_vote_id, voting_nfts)
+            };
+            Runtime::emit_event(RewardClaimed { dao_id, proposal_id, proposal_vote_id, amount: reward_bucket.amount() });
+            reward_bucket
+        }
+
+        // leftovers go back into the DAO treasury
+        pub fn proposal_sweep_reward(
+            &mut self,
+            admin_badges: Proof,
+            dao_id: String,
+            proposal_id: String,
+        ) {
+            dao_admin_badge::check_is_dao_admin(self.dao_admin_badges_manager, &self.dao_admin_badge_ids, admin_badges, &dao_id, dao_admin_badge::DaoPermission::ManageTreasury);
+
+            let dao = dao::get(self.dao_resource_manager, &dao_id);
+            let proposal = proposal::get(dao.proposals(), &proposal_id);
+
+            let reward_bucket = {
+                let mut proposal_reward = self.proposal_rewards.get_mut(&(dao_id.clone(), proposal_id.clone()))
+                    .unwrap_or_else(|| panic!("Proposal {} has no reward pool", proposal_id));
+                proposal_reward::sweep(&mut proposal_reward, &proposal)
+            };
+            Runtime::emit_event(RewardSwept { dao_id: dao_id.clone(), proposal_id, amount: reward_bucket.amount() });
+            if reward_bucket.is_empty() {
+                reward_bucket.drop_empty();
+            } else {
+                self.put_into_dao_treasury(&dao_id, reward_bucket);
+            }
+        }
+
+        pub fn proposal_refund_deposit(
+            &mut self,
+            proposal_nfts: Proof,
+            dao_id: String,
+            proposal_id: String,
+        ) -> Bucket {
+            let dao = dao::get(self.dao_resource_manager, &dao_id);
+            let proposal = proposal::get(dao.proposals(), &proposal_id);
+
+            let deposit_bucket = {
+                let mut deposit = self.proposal_deposits.get_mut(&(dao_id.clone(), proposal_id.clone()))
+                    .unwrap_or_else(|| panic!("Proposal {} has no deposit", proposal_id));
+                proposal_deposit::refund(&mut deposit, &proposal, dao.proposals(), &proposal_id, proposal_nfts)
+            };
+            Runtime::emit_event(DepositRefunded { dao_id, proposal_id, amount: deposit_bucket.amount() });
+            deposit_bucket
+        }
+
+        pub fn proposal_slash_deposit(&mut self, dao_id: String, proposal_id: String) {
+            let dao = dao::get(self.dao_resource_manager, &dao_id);
+            let proposal = proposal::get(dao.proposals(), &proposal_id);
+
+            let (deposit, slash_target) = {
+                let mut deposit = self.proposal_deposits.get_mut(&(dao_id.clone(), proposal_id.clone()))
+                    .unwrap_or_else(|| panic!("Proposal {} has no deposit", proposal_id));
+                proposal_deposit::slash(&mut deposit, &proposal)
+            };
+            self.put_slashed_deposit(&dao_id, &proposal_id, deposit, slash_target);
+        }
+
+        pub fn dao_get(&self, dao_id: String) -> views::DaoView {
+            dao::get(self.dao_resource_manager, &dao_id).view()
+        }
+
+        pub fn dao_list_proposals(&self, dao_id: String, offset: u64, limit: u64) -> Vec<String> {
+            assert!(limit <= dao_registry::MAX_PAGE_SIZE, "At most {} proposals can be listed at once", dao_registry::MAX_PAGE_SIZE);
+            match self.dao_proposal_ids.get(&dao_id) {
+                Some(proposal_ids) => proposal_ids.iter()
+                    .skip(offset as usize)
+                    .take(limit as usize)
+                    .cloned()
+                    .collect(),
+                None => Vec::new(),
+            }
+        }
+
+        pub fn dao_count(&self) -> u64 {
+            dao_registry::count(&self.dao_registry)
+        }
+
+        pub fn dao_list(&self, offset: u64, limit: u64) -> Vec<dao_registry::DaoRegistryEntry> {
+            dao_registry::list(&self.dao_registry, offset, limit)
+        }
+
+        pub fn dao_find_by_slug(&self, slug: String) -> Option<dao_registry::DaoRegistryEntry> {
+            dao_registry::find_by_slug(&self.dao_registry, &slug)
+        }
+
+        pub fn proposal_get(&self, dao_id: String, proposal_id: String) -> views::ProposalView {
+            let dao = dao::get(self.dao_resource_manager, &dao_id);
+            proposal::get(dao.proposals(), &proposal_id).view()
+        }
+
+        pub fn proposal_result_get(&self, dao_id: String, proposal_id: String) -> Option<views::ProposalResultView> {
+            let dao = dao::get(self.dao_resource_manager, &dao_id);
+            let proposal = proposal::get(dao.proposals(), &proposal_id);
+            proposal.result().map(|result| result.view())
+        }
+
+        pub fn proposal_has_voted(&self, dao_id: String, proposal_id: String, nft: NonFungibleLocalId) -> bool {
+            let dao = dao::get(self.dao_resource_manager, &dao_id);
+            proposal::get(dao.proposals(), &proposal_id).has_voted(&nft)
+        }
+
+        fn add_proposal(
+            &mut self,
+            mut payment: Bucket,
+            dao_id: String,
+            proposal_id: String,
+            title: String,
+            proposal_abstract: String,
+            specification: String,
+            voting_start: String,
+            voting_start_epoch: Option<u64>,
+            voting_end: String,
+            voting_end_epoch: Option<u64>,
+            voting_times: Option<proposal::VotingTimes>,
+            created: String,
+            info_url: String,
+            key_image_url: Option<String>,
+            additional_data: HashMap<String, String>,
+            reward: Option<Bucket>,
+            status: proposal::ProposalStatus,
+            proposer: Proposer,
+        ) -> (Bucket, Bucket) {
+            validation::check_proposal(&proposal_id, &title, &proposal_abstract, &specification, &voting_start, &voting_end, &created, &info_url, &additional_data);
+            if let Some(key_image_url) = &key_image_url {
+                validation::check_url("Key image URL", key_image_url);
+            }
+
+            let price = self.proposal_price(&dao_id, payment.resource_address());
+            let fee = payment.take(price);
+            self.put_into_undistributed_fees(&dao_id, fee);
+
+            let settings = dao_settings::get(&self.dao_settings, &dao_id);
+            if settings.proposal_deposit > Decimal::zero() {
+                assert_eq!(payment.resource_address(), self.crumb_fees.resource_address(), "Proposal deposit must be paid in CRUMBS");
+                let key = (dao_id.clone(), proposal_id.clone());
+                assert!(self.proposal_deposits.get(&key).is_none(), "Deposit for proposal {} already exists", proposal_id);
+                let deposit = payment.take(settings.proposal_deposit);
+                self.proposal_deposits.insert(key, proposal_deposit::escrow(&settings, deposit));
+            }
+
+            let proposal_non_fungible_id = utils::create_non_fungible_id_of_uuid(&proposal_id);
+            if let Some(reward) = reward {
+                let key = (dao_id.clone(), proposal_id.clone());
+                assert!(self.proposal_rewards.get(&key).is_none(), "Reward pool for proposal {} already exists", proposal_id);
+                self.proposal_rewards.insert(key, proposal_reward::fund(reward));
+            }
+
+            let dao = dao::get(self.dao_resource_manager, &dao_id);
+            dao.check_active();
+            dao.dao_type().check_nft_votes(dao.governance_resource());
+            let proposal = proposal::create(
+                proposal_id.clone(),
+                title.clone(),
+                proposal_abstract,
+                specification,
+                dao.dao_type(),
+                dao.governance_resource(),
+                voting_start,
+                voting_start_epoch,
+                voting_end,
+                voting_end_epoch,
+                voting_times,
                 created,
+                info_url,
+                key_image_url.map(Url::of).unwrap_or_else(|| dao.key_image_url()),
                 additional_data,
+                status.clone(),
             );
+
+            let proposal_bucket = dao
+                .proposals()
+                .mint_non_fungible(&proposal_non_fungible_id, proposal);
+            self.proposal_extensions.insert((dao_id.clone(), proposal_id.clone()), migration::proposal_extension(None));
+            self.add_to_proposal_index(&dao_id, proposal_id.clone());
+            Runtime::emit_event(ProposalCreated {
+                dao_id,
+                proposal_id,
+                title,
+                status,
+                proposer,
+                voting_start_epoch,
+                voting_end_epoch,
+                voting_times,
+            });
+
+            (proposal_bucket, payment)
+        }
+
+        fn take_dao_creation_fee(&self, payment: &mut Bucket) -> Bucket {
+            assert_eq!(payment.resource_address(), self.crumb_fees.resource_address(), "DAO creation fee must be paid in CRUMBS");
+            payment.take(self.dao_creation_price)
+        }
+
+        fn create_dao(&mut self, dao_id: String, data: dao_application::DaoApplicationData) -> (Bucket, Bucket) {
+            dao_application::check(&dao_id, &data);
+            dao_registry::register(&mut self.dao_registry, &dao_id, &data.name);
+
+            let dao_bucket = dao::create(
+                self.owner_badge_address,
+                &self.owner_badge_access_rule,
+                self.component_address,
+                self.dao_resource_manager,
+                dao_id.clone(),
+                data.name.clone(),
+                data.info_url,
+                data.key_image_url,
+                data.dao_type,
+                data.governance_resource,
+                data.about,
+                data.general,
+                data.created,
+                data.rules,
+                data.additional_data,
+            );
+
+            Runtime::emit_event(DaoCreated { dao_id: dao_id.clone(), name: data.name, governance_resource: data.governance_resource });
+
+            self.dao_extensions.insert(dao_id.clone(), migration::dao_extension(None));
+
+            let admin_badge_bucket = self.dao_mint_admin_badge_by_owner(dao_id, dao_admin_badge::DaoAdminRole::Owner, None);
+            (dao_bucket, admin_badge_bucket)
+        }
+
+        fn proposal_price(&self, dao_id: &String, resource_address: ResourceAddress) -> Decimal {
+            let is_crumbs = resource_address == self.crumb_fees.resource_address();
+            let regular_price = if is_crumbs {
+                self.proposal_creation_price
+            } else {
+                proposal_fee::resource_price(&self.fee_resources, resource_address)
+            };
+            let proposal_count = self.dao_proposal_ids.get(dao_id).map(|proposal_ids| proposal_ids.len() as u64).unwrap_or(0);
+
+            let settings = dao_settings::get(&self.dao_settings, dao_id);
+            proposal_fee::price(settings.proposal_fee_override, regular_price, self.proposal_creation_price, is_crumbs, proposal_count)
+        }
+
+        fn add_to_proposal_index(&mut self, dao_id: &String, proposal_id: String) {
+            if let Some(mut proposal_ids) = self.dao_proposal_ids.get_mut(dao_id) {
+                proposal_ids.push(proposal_id);
+                return;
+            }
+            self.dao_proposal_ids.insert(dao_id.clone(), vec![proposal_id]);
+        }
+
+        fn max_admins(&self, dao_id: &String) -> u32 {
+            let settings = dao_settings::get(&self.dao_settings, dao_id);
+            settings.max_admins.unwrap_or(self.default_max_admins)
+        }
+
+        // the proposal resource and admin badges carry the DAO name and logo
+        fn propagate_dao_branding(&self, dao_id: &String) {
+            let dao = dao::get(self.dao_resource_manager, dao_id);
+            proposal::update_resource_metadata(dao.proposals(), &dao.name(), dao.key_image_url());
+            dao_admin_badge::rebrand(self.dao_admin_badges_manager, &self.dao_admin_badge_ids, dao_id, &dao.name(), dao.key_image_url());
+        }
+
+        fn set_dao_status(&mut self, dao_id: &String, status: dao::DaoStatus) {
+            dao::set_status(self.dao_resource_manager, dao_id, status);
+            Runtime::emit_event(DaoStatusChanged { dao_id: dao_id.clone(), status, frozen: false });
+        }
+
+        fn set_dao_frozen(&mut self, dao_id: &String, frozen: bool) {
+            dao::set_frozen(self.dao_resource_manager, dao_id, frozen);
+            let status = dao::get(self.dao_resource_manager, dao_id).status();
+            Runtime::emit_event(DaoStatusChanged { dao_id: dao_id.clone(), status, frozen });
+        }
+
+        fn check_no_approval_required(&self, dao_id: &String) {
+            let settings = dao_settings::get(&self.dao_settings, dao_id);
+            assert!(settings.approval_threshold <= 1, "DAO {} requires {} admin approvals for this action. Submit it as operation", dao_id, settings.approval_threshold);
+        }
+
+        fn execute_operation_if_approved(&mut self, dao_id: &String, operation_id: u64) -> Option<Bucket> {
+            let settings = dao_settings::get(&self.dao_settings, dao_id);
+            let (approval_count, operation) = {
+                let pending_dao_operation = self.dao_operations.get(&operation_id).unwrap();
+                let approval_count = pending_dao_operation.approval_count(|badge_id| dao_admin_badge::is_usable(self.dao_admin_badges_manager, &self.dao_admin_badge_ids, dao_id, badge_id));
+                (approval_count, pending_dao_operation.operation())
+            };
+            if approval_count < settings.approval_threshold {
+                return None;
+            }
+
+            self.dao_operations.remove(&operation_id);
+            Runtime::emit_event(OperationExecuted { dao_id: dao_id.clone(), operation_id });
+            self.execute_operation(dao_id, operation)
+        }
+
+        fn execute_operation(&mut self, dao_id: &String, operation: dao_operation::DaoOperation) -> Option<Bucket> {
+            dao::get(self.dao_resource_manager, dao_id).check_not_frozen();
+            match operation {
+                dao_operation::DaoOperation::UpdateDao { patch } => {
+                    validation::check_dao_patch(&patch);
+                    if let Some(name) = &patch.name {
+                        dao_registry::rename(&mut self.dao_registry, dao_id, name);
+                    }
+                    let applied_patch = dao::patch(self.dao_resource_manager, dao_id, patch);
+                    // only a changed name or logo is written to the proposal resource and admin badges
+                    if applied_patch.name.is_some() || applied_patch.key_image_url.is_some() {
+                        self.propagate_dao_branding(dao_id);
+                    }
+                    if !applied_patch.is_empty() {
+                        let revision = dao::record_update(self.dao_resource_manager, dao_id);
+                        Runtime::emit_event(DaoUpdated { dao_id: dao_id.clone(), revision, patch: applied_patch });
+                    }
+                    None
+                }
+                dao_operation::DaoOperation::UpdateAdditionalData { changes } => {
+                    let dao = dao::get(self.dao_resource_manager, dao_id);
+                    let mut dao_additional_data = dao.additional_data();
+                    let mut dao_additional_data_vec = dao.additional_data_vec();
+                    additional_data::apply(&mut dao_additional_data, &mut dao_additional_data_vec, changes.clone());
+
+                    if dao_additional_data != dao.additional_data() || dao_additional_data_vec != dao.additional_data_vec() {
+                        dao::set_additional_data(self.dao_resource_manager, dao_id, dao_additional_data, dao_additional_data_vec);
+                        let revision = dao::record_update(self.dao_resource_manager, dao_id);
+                        Runtime::emit_event(DaoAdditionalDataUpdated { dao_id: dao_id.clone(), revision, changes });
+                    }
+                    None
+                }
+                dao_operation::DaoOperation::MintAdminBadge { role, expires_epoch } => {
+                    let dao = dao::get(self.dao_resource_manager, dao_id);
+                    let max_admins = self.max_admins(dao_id);
+                    let dao_admin_badge_bucket = dao_admin_badge::mint(self.dao_admin_badges_manager, &self.dao_admin_badge_ids, dao_id.clone(), dao.name(), dao.key_image_url(), role, expires_epoch, max_admins);
+                    emit_admin_badge_minted(dao_id.clone(), &dao_admin_badge_bucket, role);
+                    Some(dao_admin_badge_bucket)
+                }
+                dao_operation::DaoOperation::RevokeAdminBadge { badge_id, holder_vault } => {
+                    let settings = dao_settings::get(&self.dao_settings, dao_id);
+                    dao_admin_badge::revoke(self.dao_admin_badges_manager, &self.dao_admin_badge_ids, dao_id, &badge_id, holder_vault, settings.approval_threshold);
+                    Runtime::emit_event(AdminBadgeRevoked { dao_id: dao_id.clone(), badge_id });
+                    None
+                }
+                dao_operation::DaoOperation::ReissueAdminBadge { badge_id, holder_vault } => {
+                    let dao = dao::get(self.dao_resource_manager, dao_id);
+                    let settings = dao_settings::get(&self.dao_settings, dao_id);
+                    let dao_admin_badge_bucket = dao_admin_badge::reissue(self.dao_admin_badges_manager, &self.dao_admin_badge_ids, dao_id, &badge_id, holder_vault, dao.name(), dao.key_image_url(), settings.approval_threshold);
+                    let role = dao_admin_badge_bucket.as_non_fungible().non_fungible::<dao_admin_badge::DaoAdminBadge>().data().role();
+                    emit_admin_badge_minted(dao_id.clone(), &dao_admin_badge_bucket, role);
+                    Runtime::emit_event(AdminBadgeRevoked { dao_id: dao_id.clone(), badge_id });
+                    Some(dao_admin_badge_bucket)
+                }
+                dao_operation::DaoOperation::RenewAdminBadge { badge_id, expires_epoch } => {
+                    dao_admin_badge::renew(self.dao_admin_badges_manager, &self.dao_admin_badge_ids, dao_id, &badge_id, expires_epoch);
+                    Runtime::emit_event(AdminBadgeRenewed { dao_id: dao_id.clone(), badge_id, expires_epoch });
+                    None
+                }
+                dao_operation::DaoOperation::WithdrawTreasury { resource_address } => {
+                    let treasury_bucket = self.dao_treasuries.get_mut(&(dao_id.clone(), resource_address))
+                        .unwrap_or_else(|| panic!("DAO {} has no treasury of resource {:?}", dao_id, resource_address))
+                        .take_all();
+                    Runtime::emit_event(TreasuryWithdrawn { dao_id: dao_id.clone(), resource_address, amount: treasury_bucket.amount() });
+                    Some(treasury_bucket)
+                }
+                dao_operation::DaoOperation::SetProposalDeposit { proposal_deposit, quorum, deposit_slash_target } => {
+                    assert!(proposal_deposit >= Decimal::zero(), "Proposal deposit must not be negative");
+                    assert!(quorum >= Decimal::zero(), "Quorum must not be negative");
+
+                    let mut settings = dao_settings::get(&self.dao_settings, dao_id);
+                    settings.proposal_deposit = proposal_deposit;
+                    settings.quorum = quorum;
+                    settings.deposit_slash_target = deposit_slash_target;
+                    self.dao_settings.insert(dao_id.clone(), settings.clone());
+                    Runtime::emit_event(DaoSettingsUpdated { dao_id: dao_id.clone(), settings });
+                    None
+                }
+                dao_operation::DaoOperation::SetMemberProposals { member_proposal_threshold, member_proposal_sponsorship, member_proposal_seconds } => {
+                    if let Some(threshold) = member_proposal_threshold {
+                        assert!(threshold > Decimal::zero(), "Member proposal threshold must be positive");
+                    }
+                    if member_proposal_seconds > 0 {
+                        let governance_resource = dao::get(self.dao_resource_manager, dao_id).governance_resource();
+                        assert!(!governance_resource.is_fungible(), "Member seconding requires a non-fungible governance resource");
+                    }
+
+                    let mut settings = dao_settings::get(&self.dao_settings, dao_id);
+                    settings.member_proposal_threshold = member_proposal_threshold;
+                    settings.member_proposal_sponsorship = member_proposal_sponsorship;
+                    settings.member_proposal_seconds = member_proposal_seconds;
+                    self.dao_settings.insert(dao_id.clone(), settings.clone());
+                    Runtime::emit_event(DaoSettingsUpdated { dao_id: dao_id.clone(), settings });
+                    None
+                }
+                dao_operation::DaoOperation::SetApprovalPolicy { approval_threshold, approval_expiry_epochs } => {
+                    let admin_count = dao_admin_badge::usable_count(self.dao_admin_badges_manager, &self.dao_admin_badge_ids, dao_id);
+                    assert!(approval_threshold >= 1 && approval_threshold as usize <= admin_count, "Approval threshold must be between 1 and the {} usable admins of the DAO", admin_count);
+
+                    let mut settings = dao_settings::get(&self.dao_settings, dao_id);
+                    settings.approval_threshold = approval_threshold;
+                    settings.approval_expiry_epochs = approval_expiry_epochs;
+                    self.dao_settings.insert(dao_id.clone(), settings.clone());
+                    Runtime::emit_event(DaoSettingsUpdated { dao_id: dao_id.clone(), settings });
+                    None
+                }
+                dao_operation::DaoOperation::SetStatus { status } => {
+                    let dao = dao::get(self.dao_resource_manager, dao_id);
+                    match status {
+                        dao::DaoStatus::Archived => dao.check_active(),
+                        dao::DaoStatus::Active => assert_eq!(dao.status(), dao::DaoStatus::Archived, "Only archived DAOs can be reactivated. DAO {} is {:?}", dao_id, dao.status()),
+                    }
+                    self.set_dao_status(dao_id, status);
+                    None
+                }
+            }
+        }
+
+        fn put_slashed_deposit(&mut self, dao_id: &String, proposal_id: &String, deposit: Bucket, slash_target: dao_settings::DepositSlashTarget) {
+            Runtime::emit_event(DepositSlashed {
+                dao_id: dao_id.clone(),
+                proposal_id: proposal_id.clone(),
+                amount: deposit.amount(),
+                slash_target,
+            });
+            match slash_target {
+                dao_settings::DepositSlashTarget::DaoTreasury => self.put_into_dao_treasury(dao_id, deposit),
+                dao_settings::DepositSlashTarget::PlatformFees => self.put_into_platform_fees(deposit),
+            }
+        }
+
+        fn put_into_dao_treasury(&mut self, dao_id: &String, bucket: Bucket) {
+            let key = (dao_id.clone(), bucket.resource_address());
+            if let Some(mut dao_treasury) = self.dao_treasuries.get_mut(&key) {
+                dao_treasury.put(bucket);
+                return;
+            }
+            self.dao_treasuries.insert(key, Vault::with_bucket(bucket));
+        }
+
+        fn put_into_platform_fees(&mut self, bucket: Bucket) {
+            if bucket.resource_address() == self.crumb_fees.resource_address() {
+                self.crumb_fees.put(bucket);
+            } else {
+                proposal_fee::put(&self.fee_resources, bucket);
+            }
+        }
+
+        fn put_into_undistributed_fees(&mut self, dao_id: &String, fee: Bucket) {
+            let resource_address = fee.resource_address();
+            fee_distribution::record_collected(&self.dao_fee_records, dao_id, resource_address, fee.amount());
+
+            let key = (dao_id.clone(), resource_address);
+            if let Some(mut undistributed_fees) = self.undistributed_fees.get_mut(&key) {
+                undistributed_fees.put(fee);
+                return;
+            }
+            self.undistributed_fees.insert(key, Vault::with_bucket(fee));
         }
     }
 }
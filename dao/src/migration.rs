@@ -0,0 +1,77 @@
+use scrypto::prelude::*;
+
+use crate::{dao, proposal};
+
+// Dao and Proposal NFT schemas are fixed when their resource is created, so fields added later
+// are kept in these component side extensions with one variant per NFT version
+#[derive(ScryptoSbor, Clone)]
+pub enum VersionedDaoExtension {
+    V2(DaoExtensionV2),
+}
+
+#[derive(ScryptoSbor, Clone)]
+pub struct DaoExtensionV2 {
+    pub migrated_epoch: Option<Epoch>,
+}
+
+#[derive(ScryptoSbor, Clone)]
+pub enum VersionedProposalExtension {
+    V2(ProposalExtensionV2),
+}
+
+#[derive(ScryptoSbor, Clone)]
+pub struct ProposalExtensionV2 {
+    pub migrated_epoch: Option<Epoch>,
+}
+
+pub(crate) fn dao_extension(migrated_epoch: Option<Epoch>) -> VersionedDaoExtension {
+    VersionedDaoExtension::V2(DaoExtensionV2 { migrated_epoch })
+}
+
+pub(crate) fn proposal_extension(migrated_epoch: Option<Epoch>) -> VersionedProposalExtension {
+    VersionedProposalExtension::V2(ProposalExtensionV2 { migrated_epoch })
+}
+
+// returns false when the DAO already has the current version
+pub(crate) fn migrate_dao(
+    dao_resource_manager: ResourceManager,
+    dao_extensions: &KeyValueStore<String, VersionedDaoExtension>,
+    dao_id: &String,
+) -> bool {
+    let mut version = dao::get(dao_resource_manager, dao_id).version();
+    if version >= dao::VERSION {
+        return false;
+    }
+
+    while version < dao::VERSION {
+        match version {
+            1 => dao_extensions.insert(dao_id.clone(), dao_extension(Some(Runtime::current_epoch()))),
+            _ => panic!("No migration for DAO {} from version {}", dao_id, version),
+        }
+        version += 1;
+    }
+    dao::set_version(dao_resource_manager, dao_id, version);
+    true
+}
+
+pub(crate) fn migrate_proposal(
+    proposal_resource_manager: ResourceManager,
+    proposal_extensions: &KeyValueStore<(String, String), VersionedProposalExtension>,
+    dao_id: &String,
+    proposal_id: &String,
+) -> bool {
+    let mut version = proposal::get(proposal_resource_manager, proposal_id).version();
+    if version >= proposal::VERSION {
+        return false;
+    }
+
+    while version < proposal::VERSION {
+        match version {
+            1 => proposal_extensions.insert((dao_id.clone(), proposal_id.clone()), proposal_extension(Some(Runtime::current_epoch()))),
+            _ => panic!("No migration for proposal {} from version {}", proposal_id, version),
+        }
+        version += 1;
+    }
+    proposal::set_version(proposal_resource_manager, proposal_id, version);
+    true
+}
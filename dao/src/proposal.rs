@@ -1,10 +1,12 @@
 use scrypto::prelude::*;
 
+use crate::dao::DaoType;
 use crate::proposal_option::proposal_option::ProposalOption;
 use crate::proposal_result::calc_result;
 use crate::proposal_result::proposal_result::ProposalResult;
 use crate::proposal_vote::proposal_vote::ProposalVote;
 use crate::utils;
+use crate::views::{ProposalOptionView, ProposalView};
 
 pub(crate) fn create_resource_manager(
     owner_badge_address: ResourceAddress,
@@ -13,8 +15,8 @@ pub(crate) fn create_resource_manager(
     dao_name: &str,
     icon_url: Url,
 ) -> ResourceManager {
-    let name = format!("{} Proposals", dao_name);
-    let description = format!("These are the Proposals for {}", dao_name);
+    let name = resource_name(dao_name);
+    let description = resource_description(dao_name);
     let resource_manager =
         ResourceBuilder::new_string_non_fungible::<Proposal>(OwnerRole::Fixed(owner_badge_access_rule.clone()))
             .metadata(metadata!(
@@ -64,20 +66,58 @@ pub(crate) fn create_resource_manager(
     resource_manager
 }
 
+pub(crate) fn update_resource_metadata(proposal_resource_manager: ResourceManager, dao_name: &str, icon_url: Url) {
+    proposal_resource_manager.set_metadata("name", resource_name(dao_name));
+    proposal_resource_manager.set_metadata("description", resource_description(dao_name));
+    proposal_resource_manager.set_metadata("icon_url", icon_url);
+}
+
+fn resource_name(dao_name: &str) -> String {
+    format!("{} Proposals", dao_name)
+}
+
+fn resource_description(dao_name: &str) -> String {
+    format!("These are the Proposals for {}", dao_name)
+}
+
+// additional data key holding the admin badge a revocation proposal votes on
+pub(crate) const REVOKE_ADMIN_BADGE_KEY: &str = "revoke_admin_badge";
+
+#[derive(ScryptoSbor, PartialEq, Clone, Debug)]
+pub enum ProposalStatus {
+    PendingSponsorship,
+    Active,
+    Cancelled,
+}
+
+// UTC voting window enforced with the Clock instead of the voting epochs
+#[derive(ScryptoSbor, PartialEq, Clone, Copy, Debug)]
+pub struct VotingTimes {
+    pub start: Instant,
+    pub end: Instant,
+}
+
+// version 2 keeps later added fields in the component side proposal extension
+pub(crate) const VERSION: u32 = 2;
+
 #[derive(ScryptoSbor, NonFungibleData)]
 pub(crate) struct Proposal {
     proposal_id: String,
+    #[mutable]
+    version: u32,
     name: String,
     description: String,
     title: String,
     p_abstract: String,
     specification: String,
-    dao_type: String,
+    dao_type: DaoType,
     governance_resource: ResourceAddress,
     voting_start: String,
-    voting_start_epoch: Epoch,
+    // proposals are scheduled either by epochs or by voting times
+    voting_start_epoch: Option<Epoch>,
     voting_end: String,
-    voting_end_epoch: Epoch,
+    voting_end_epoch: Option<Epoch>,
+    voting_times: Option<VotingTimes>,
     created: String,
     created_epoch: Epoch,
     #[mutable]
@@ -96,6 +136,160 @@ pub(crate) struct Proposal {
     nfts_voted: HashSet<NonFungibleLocalId>,
     #[mutable]
     result: Option<ProposalResult>,
+    #[mutable]
+    status: ProposalStatus,
+}
+
+impl Proposal {
+    pub(crate) fn version(&self) -> u32 {
+        self.version
+    }
+
+    pub(crate) fn governance_resource(&self) -> ResourceAddress {
+        self.governance_resource.clone()
+    }
+
+    pub(crate) fn voting_end_epoch(&self) -> Option<Epoch> {
+        self.voting_end_epoch
+    }
+
+    pub(crate) fn voting_times(&self) -> Option<VotingTimes> {
+        self.voting_times
+    }
+
+    pub(crate) fn has_voting_started(&self) -> bool {
+        match self.voting_times {
+            Some(voting_times) => Clock::current_time_is_at_or_after(voting_times.start, TimePrecision::Minute),
+            None => Runtime::current_epoch().number() >= self.scheduled_epoch(self.voting_start_epoch).number(),
+        }
+    }
+
+    pub(crate) fn has_voting_ended(&self) -> bool {
+        match self.voting_times {
+            Some(voting_times) => Clock::current_time_is_strictly_after(voting_times.end, TimePrecision::Minute),
+            None => Runtime::current_epoch().number() > self.scheduled_epoch(self.voting_end_epoch).number(),
+        }
+    }
+
+    pub(crate) fn voting_start_label(&self) -> String {
+        match self.voting_times {
+            Some(_) => self.voting_start.clone(),
+            None => format!("epoch {}", self.scheduled_epoch(self.voting_start_epoch).number()),
+        }
+    }
+
+    pub(crate) fn voting_end_label(&self) -> String {
+        match self.voting_times {
+            Some(_) => self.voting_end.clone(),
+            None => format!("epoch {}", self.scheduled_epoch(self.voting_end_epoch).number()),
+        }
+    }
+
+    // proposals without voting times are always created with both epochs
+    fn scheduled_epoch(&self, epoch: Option<Epoch>) -> Epoch {
+        epoch.unwrap_or_else(|| panic!("Proposal {} has no voting epochs", self.proposal_id))
+    }
+
+    pub(crate) fn votes(&self) -> &Vec<ProposalVote> {
+        &self.votes
+    }
+
+    pub(crate) fn result(&self) -> Option<&ProposalResult> {
+        self.result.as_ref()
+    }
+
+    pub(crate) fn votes_power(&self) -> Decimal {
+        self.votes.iter().fold(Decimal::zero(), |power, vote| power + vote.power())
+    }
+
+    pub(crate) fn is_cancelled(&self) -> bool {
+        self.status == ProposalStatus::Cancelled
+    }
+
+    // voters decide on the proposal as it was when voting started
+    pub(crate) fn check_editable(&self) {
+        assert!(!self.is_cancelled(), "Proposal {} has been cancelled", self.proposal_id);
+        assert!(!self.has_voting_started(), "Proposal {} can only be changed before voting start {}", self.proposal_id, self.voting_start_label());
+    }
+
+    pub(crate) fn has_voted(&self, nft: &NonFungibleLocalId) -> bool {
+        self.nfts_voted.contains(nft)
+    }
+
+    pub(crate) fn view(&self) -> ProposalView {
+        let options = self.options.iter().map(|option| ProposalOptionView {
+            option_id: option.id(),
+            rank: option.rank(),
+            option: option.option(),
+        }).collect();
+
+        ProposalView {
+            proposal_id: self.proposal_id.clone(),
+            version: self.version,
+            title: self.title.clone(),
+            proposal_abstract: self.p_abstract.clone(),
+            specification: self.specification.clone(),
+            governance_resource: self.governance_resource,
+            voting_start: self.voting_start.clone(),
+            voting_start_epoch: self.voting_start_epoch,
+            voting_end: self.voting_end.clone(),
+            voting_end_epoch: self.voting_end_epoch,
+            voting_times: self.voting_times,
+            created: self.created.clone(),
+            created_epoch: self.created_epoch,
+            info_url: self.info_url.clone(),
+            key_image_url: self.key_image_url.clone(),
+            additional_data: self.additional_data.clone(),
+            additional_data_vec: self.additional_data_vec.clone(),
+            status: self.status.clone(),
+            options,
+            vote_count: self.votes.len() as u32,
+        }
+    }
+
+    pub(crate) fn additional_data(&self) -> HashMap<String, String> {
+        self.additional_data.clone()
+    }
+
+    pub(crate) fn additional_data_vec(&self) -> HashMap<String, Vec<String>> {
+        self.additional_data_vec.clone()
+    }
+
+    pub(crate) fn additional_data_value(&self, key: &str) -> Option<String> {
+        self.additional_data.get(key).cloned()
+    }
+
+    // the badge a revocation proposal votes on must stay what voters see
+    pub(crate) fn check_revoked_badge_unchanged(&self, additional_data: &HashMap<String, String>) {
+        assert_eq!(additional_data.get(REVOKE_ADMIN_BADGE_KEY).cloned(), self.additional_data_value(REVOKE_ADMIN_BADGE_KEY), "Badge of a revocation proposal can not be changed");
+    }
+
+    // a proposal is approved when voting between at least two options ended with votes and quorum
+    // and the option with the lowest rank got the most power
+    pub(crate) fn is_approved(&self, quorum: Decimal) -> bool {
+        if self.status != ProposalStatus::Active || !self.has_voting_ended() || self.options.len() < 2 {
+            return false;
+        }
+        let votes_power = self.votes_power();
+        if votes_power.is_zero() || votes_power < quorum {
+            return false;
+        }
+
+        let approving_option = match self.options.iter().min_by_key(|option| option.rank()) {
+            Some(option) => option,
+            None => return false,
+        };
+        let option_power = |option_id: String| {
+            self.votes.iter()
+                .filter(|vote| vote.option_id() == option_id)
+                .fold(Decimal::zero(), |power, vote| power + vote.power())
+        };
+
+        let approving_power = option_power(approving_option.id());
+        self.options.iter()
+            .filter(|option| option.id() != approving_option.id())
+            .all(|option| option_power(option.id()) < approving_power)
+    }
 }
 
 pub(crate) fn create(
@@ -103,25 +297,41 @@ pub(crate) fn create(
     title: String,
     p_abstract: String,
     specification: String,
-    dao_type: String,
+    dao_type: DaoType,
     governance_resource: ResourceAddress,
     voting_start: String,
-    voting_start_epoch: u64,
+    voting_start_epoch: Option<u64>,
     voting_end: String,
-    voting_end_epoch: u64,
+    voting_end_epoch: Option<u64>,
+    voting_times: Option<VotingTimes>,
     created: String,
     info_url: String,
     key_image_url: Url,
     additional_data: HashMap<String, String>,
+    status: ProposalStatus,
 ) -> Proposal {
     let current_epoch = Runtime::current_epoch();
 
-    assert!(current_epoch.number() < voting_start_epoch, "Voting start epoch {} is in the past. Current epoch {}", voting_start_epoch, current_epoch.number());
-    assert!(voting_start_epoch <= voting_end_epoch, "Voting start epoch {} is after voting end epoch {}", voting_start_epoch, voting_end_epoch);
+    // only the enforced schedule is stored, so the shown window is the enforced one
+    let (voting_start, voting_end) = match (voting_start_epoch, voting_end_epoch, voting_times) {
+        (Some(voting_start_epoch), Some(voting_end_epoch), None) => {
+            assert!(current_epoch.number() < voting_start_epoch, "Voting start epoch {} is in the past. Current epoch {}", voting_start_epoch, current_epoch.number());
+            assert!(voting_start_epoch <= voting_end_epoch, "Voting start epoch {} is after voting end epoch {}", voting_start_epoch, voting_end_epoch);
+            (voting_start, voting_end)
+        }
+        (None, None, Some(voting_times)) => {
+            assert!(Clock::current_time_is_strictly_before(voting_times.start, TimePrecision::Minute), "Voting start {:?} is in the past", voting_times.start);
+            assert!(voting_times.start.seconds_since_unix_epoch <= voting_times.end.seconds_since_unix_epoch, "Voting start {:?} is after voting end {:?}", voting_times.start, voting_times.end);
+            // the shown times are derived from the enforced ones
+            (utc_string_of(voting_times.start), utc_string_of(voting_times.end))
+        }
+        _ => panic!("A proposal is scheduled either by voting start and end epochs or by voting times"),
+    };
 
     let info_unchecked_url = Url::of(info_url);
     let proposal = Proposal {
         proposal_id: id,
+        version: VERSION,
         name: title.clone(),
         description: p_abstract.clone(),
         title,
@@ -130,9 +340,10 @@ pub(crate) fn create(
         dao_type,
         governance_resource,
         voting_start,
-        voting_start_epoch: Epoch::of(voting_start_epoch),
+        voting_start_epoch: voting_start_epoch.map(Epoch::of),
         voting_end,
-        voting_end_epoch: Epoch::of(voting_end_epoch),
+        voting_end_epoch: voting_end_epoch.map(Epoch::of),
+        voting_times,
         created,
         created_epoch: current_epoch,
         info_url: info_unchecked_url,
@@ -143,11 +354,55 @@ pub(crate) fn create(
         votes: Vec::new(),
         nfts_voted: HashSet::new(),
         result: None,
+        status,
     };
 
     proposal
 }
 
+pub(crate) fn get(proposal_resource_manager: ResourceManager, proposal_id: &str) -> Proposal {
+    let proposal_fungible_id = utils::create_non_fungible_id_of_uuid(proposal_id);
+    let proposal: Proposal = proposal_resource_manager.get_non_fungible_data(&proposal_fungible_id);
+    proposal
+}
+
+pub(crate) fn set_additional_data(
+    proposal_resource_manager: ResourceManager,
+    proposal_id: &str,
+    additional_data: HashMap<String, String>,
+    additional_data_vec: HashMap<String, Vec<String>>,
+) {
+    let proposal_fungible_id = utils::create_non_fungible_id_of_uuid(proposal_id);
+    proposal_resource_manager.update_non_fungible_data(&proposal_fungible_id, "additional_data", additional_data);
+    proposal_resource_manager.update_non_fungible_data(&proposal_fungible_id, "additional_data_vec", additional_data_vec);
+}
+
+pub(crate) fn update_metadata(
+    proposal_resource_manager: ResourceManager,
+    proposal_id: &str,
+    info_url: Option<Url>,
+    key_image_url: Option<Url>,
+    additional_data: Option<HashMap<String, String>>,
+) {
+    assert!(info_url.is_some() || key_image_url.is_some() || additional_data.is_some(), "No proposal fields to update provided");
+
+    let proposal_fungible_id = utils::create_non_fungible_id_of_uuid(proposal_id);
+    if let Some(info_url) = info_url {
+        proposal_resource_manager.update_non_fungible_data(&proposal_fungible_id, "info_url", info_url);
+    }
+    if let Some(key_image_url) = key_image_url {
+        proposal_resource_manager.update_non_fungible_data(&proposal_fungible_id, "key_image_url", key_image_url);
+    }
+    if let Some(additional_data) = additional_data {
+        proposal_resource_manager.update_non_fungible_data(&proposal_fungible_id, "additional_data", additional_data);
+    }
+}
+
+pub(crate) fn set_version(proposal_resource_manager: ResourceManager, proposal_id: &str, version: u32) {
+    let proposal_fungible_id = utils::create_non_fungible_id_of_uuid(proposal_id);
+    proposal_resource_manager.update_non_fungible_data(&proposal_fungible_id, "version", version);
+}
+
 pub(crate) fn add_option(
     proposal_resource_manager: ResourceManager,
     proposal_id: String,
@@ -160,8 +415,9 @@ pub(crate) fn add_option(
     let mut proposal: Proposal =
         proposal_resource_manager.get_non_fungible_data(&proposal_fungible_id);
 
-    let current_epoch = Runtime::current_epoch().number();
-    assert!(current_epoch < proposal.voting_start_epoch.number(), "Options to proposal can only be added epoch before voting start epoch {}. Current epoch {}", proposal.voting_start_epoch.number(), current_epoch);
+    assert!(!proposal.is_cancelled(), "Proposal {} has been cancelled", proposal_id);
+
+    assert!(!proposal.has_voting_started(), "Options to proposal can only be added before voting start {}", proposal.voting_start_label());
 
     for existing_option in proposal.options.iter() {
         assert_ne!(existing_option.id(), option_id, "Option with id {} already exists", option_id);
@@ -187,7 +443,7 @@ pub(crate) fn mint_nft_vote(
     voting_nfts: Proof,
     created: String,
     additional_data: HashMap<String, String>,
-) {
+) -> Decimal {
     let proposal_fungible_id = utils::create_non_fungible_id_of_uuid(&proposal_id);
     let mut proposal: Proposal =
         proposal_resource_manager.get_non_fungible_data(&proposal_fungible_id);
@@ -214,7 +470,8 @@ pub(crate) fn mint_nft_vote(
         proposal.nfts_voted,
     );
 
-    let vote = ProposalVote::new(proposal_vote_id, proposal_option_id, entity, Decimal::from(nfts_to_vote.len()), nfts_to_vote, created, additional_data);
+    let vote_power = Decimal::from(nfts_to_vote.len());
+    let vote = ProposalVote::new(proposal_vote_id, proposal_option_id, entity, vote_power, nfts_to_vote, created, additional_data);
     proposal.votes.push(vote);
     proposal_resource_manager.update_non_fungible_data(
         &proposal_fungible_id,
@@ -223,9 +480,12 @@ pub(crate) fn mint_nft_vote(
     );
 
     update_result(&proposal_resource_manager, &proposal_fungible_id);
+    vote_power
 }
 
 fn check_vote_against_proposal(proposal_vote_id: &String, proposal_option_id: &String, proposal: &Proposal) {
+    assert!(proposal.status == ProposalStatus::Active, "Proposal {} is not active. Status {:?}", proposal.proposal_id, proposal.status);
+
     if proposal.options.iter().all(|option| option.id() != *proposal_option_id) {
         panic!("Proposal Option with id {} does not exist", *proposal_option_id);
     }
@@ -234,9 +494,38 @@ fn check_vote_against_proposal(proposal_vote_id: &String, proposal_option_id: &S
         panic!("Vote with id {} has already voted", *proposal_vote_id);
     }
 
-    let current_epoch = Runtime::current_epoch().number();
-    assert!(current_epoch >= proposal.voting_start_epoch.number(), "Voting has not started yet. Current epoch: {}, Voting start epoch: {}", current_epoch, proposal.voting_start_epoch.number());
-    assert!(current_epoch <= proposal.voting_end_epoch.number(), "Voting has ended. Current epoch: {}, Voting end epoch: {}", current_epoch, proposal.voting_end_epoch.number());
+    assert!(proposal.has_voting_started(), "Voting has not started yet. Voting start {}", proposal.voting_start_label());
+    assert!(!proposal.has_voting_ended(), "Voting has ended. Voting end {}", proposal.voting_end_label());
+}
+
+pub(crate) fn cancel(proposal_resource_manager: ResourceManager, proposal_id: &str) {
+    let proposal_fungible_id = utils::create_non_fungible_id_of_uuid(proposal_id);
+    let proposal: Proposal =
+        proposal_resource_manager.get_non_fungible_data(&proposal_fungible_id);
+
+    assert!(!proposal.is_cancelled(), "Proposal {} has already been cancelled", proposal_id);
+    assert!(!proposal.has_voting_ended(), "Proposal can only be cancelled until voting end {}", proposal.voting_end_label());
+
+    proposal_resource_manager.update_non_fungible_data(
+        &proposal_fungible_id,
+        "status",
+        ProposalStatus::Cancelled,
+    );
+}
+
+pub(crate) fn activate(proposal_resource_manager: ResourceManager, proposal_id: &str) {
+    let proposal_fungible_id = utils::create_non_fungible_id_of_uuid(proposal_id);
+    let proposal: Proposal =
+        proposal_resource_manager.get_non_fungible_data(&proposal_fungible_id);
+
+    assert!(proposal.status == ProposalStatus::PendingSponsorship, "Proposal {} is not pending sponsorship. Status {:?}", proposal_id, proposal.status);
+    assert!(!proposal.has_voting_ended(), "Proposal can only be sponsored until voting end {}", proposal.voting_end_label());
+
+    proposal_resource_manager.update_non_fungible_data(
+        &proposal_fungible_id,
+        "status",
+        ProposalStatus::Active,
+    );
 }
 
 fn update_result(
@@ -254,3 +543,9 @@ fn update_result(
         proposal.result,
     );
 }
+
+fn utc_string_of(instant: Instant) -> String {
+    UtcDateTime::from_instant(&instant)
+        .unwrap_or_else(|_| panic!("Time {:?} can not be shown as UTC", instant))
+        .to_string()
+}
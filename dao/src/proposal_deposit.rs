@@ -0,0 +1,61 @@
+use scrypto::prelude::*;
+
+use crate::dao_settings::{DaoSettings, DepositSlashTarget};
+use crate::proposal::Proposal;
+use crate::utils;
+
+#[derive(ScryptoSbor)]
+pub(crate) struct ProposalDeposit {
+    quorum: Decimal,
+    slash_target: DepositSlashTarget,
+    vault: Vault,
+    settled: bool,
+}
+
+pub(crate) fn escrow(dao_settings: &DaoSettings, deposit: Bucket) -> ProposalDeposit {
+    let proposal_deposit = ProposalDeposit {
+        quorum: dao_settings.quorum,
+        slash_target: dao_settings.deposit_slash_target,
+        vault: Vault::with_bucket(deposit),
+        settled: false,
+    };
+
+    proposal_deposit
+}
+
+pub(crate) fn refund(
+    proposal_deposit: &mut ProposalDeposit,
+    proposal: &Proposal,
+    proposal_resource_manager: ResourceManager,
+    proposal_id: &str,
+    proposal_nfts: Proof,
+) -> Bucket {
+    check_unsettled(proposal_deposit);
+
+    let proposal_non_fungible_id = utils::create_non_fungible_id_of_uuid(proposal_id);
+    let proposal_nfts_checked = proposal_nfts.check_with_message(proposal_resource_manager.address(), "NFTs are not Proposals of this DAO").as_non_fungible().non_fungible_local_ids();
+    assert!(proposal_nfts_checked.contains(&proposal_non_fungible_id), "You are not the proposer of proposal {}", proposal_id);
+
+    assert!(!proposal.is_cancelled(), "Deposit of a cancelled proposal is not refundable");
+    assert!(proposal.has_voting_ended(), "Deposit can only be refunded after voting end {}", proposal.voting_end_label());
+    assert!(proposal.votes_power() >= proposal_deposit.quorum, "Proposal did not reach quorum of {}. Vote power {}", proposal_deposit.quorum, proposal.votes_power());
+
+    proposal_deposit.settled = true;
+    proposal_deposit.vault.take_all()
+}
+
+pub(crate) fn slash(proposal_deposit: &mut ProposalDeposit, proposal: &Proposal) -> (Bucket, DepositSlashTarget) {
+    check_unsettled(proposal_deposit);
+
+    if !proposal.is_cancelled() {
+        assert!(proposal.has_voting_ended(), "Deposit can only be slashed after voting end {}", proposal.voting_end_label());
+        assert!(proposal.votes_power() < proposal_deposit.quorum, "Proposal reached quorum of {}. Vote power {}", proposal_deposit.quorum, proposal.votes_power());
+    }
+
+    proposal_deposit.settled = true;
+    (proposal_deposit.vault.take_all(), proposal_deposit.slash_target)
+}
+
+fn check_unsettled(proposal_deposit: &ProposalDeposit) {
+    assert!(!proposal_deposit.settled, "Deposit has already been settled");
+}
@@ -0,0 +1,77 @@
+use scrypto::prelude::*;
+
+#[derive(ScryptoSbor, PartialEq, Clone, Copy, Debug)]
+pub enum ProposalFeeOverride {
+    // price in CRUMBS, other fee resources are scaled by the same ratio
+    Price(Decimal),
+    // percentage taken off the regular price
+    Discount(Decimal),
+    // the first n proposals of the DAO are free
+    FreeProposals(u64),
+    Free,
+}
+
+#[derive(ScryptoSbor)]
+pub(crate) struct FeeResource {
+    price: Option<Decimal>,
+    vault: Vault,
+}
+
+pub(crate) fn check_override(fee_override: &ProposalFeeOverride) {
+    match fee_override {
+        ProposalFeeOverride::Price(price) => assert!(*price >= Decimal::zero(), "Proposal price must not be negative"),
+        ProposalFeeOverride::Discount(discount) => assert!(*discount >= Decimal::zero() && *discount <= dec!("100"), "Discount must be between 0 and 100 percent"),
+        ProposalFeeOverride::FreeProposals(_) | ProposalFeeOverride::Free => {}
+    }
+}
+
+pub(crate) fn set_price(fee_resources: &KeyValueStore<ResourceAddress, FeeResource>, resource_address: ResourceAddress, price: Option<Decimal>) {
+    if let Some(price) = price {
+        assert!(price >= Decimal::zero(), "Proposal price must not be negative");
+    }
+
+    if let Some(mut fee_resource) = fee_resources.get_mut(&resource_address) {
+        fee_resource.price = price;
+        return;
+    }
+    fee_resources.insert(resource_address, FeeResource { price, vault: Vault::new(resource_address) });
+}
+
+pub(crate) fn resource_price(fee_resources: &KeyValueStore<ResourceAddress, FeeResource>, resource_address: ResourceAddress) -> Decimal {
+    fee_resources.get(&resource_address)
+        .and_then(|fee_resource| fee_resource.price)
+        .unwrap_or_else(|| panic!("Resource {:?} is not accepted as proposal fee", resource_address))
+}
+
+pub(crate) fn put(fee_resources: &KeyValueStore<ResourceAddress, FeeResource>, fee: Bucket) {
+    let resource_address = fee.resource_address();
+    fee_resources.get_mut(&resource_address)
+        .unwrap_or_else(|| panic!("Resource {:?} is not accepted as proposal fee", resource_address))
+        .vault.put(fee);
+}
+
+pub(crate) fn take_all(fee_resources: &KeyValueStore<ResourceAddress, FeeResource>, resource_address: ResourceAddress) -> Bucket {
+    fee_resources.get_mut(&resource_address)
+        .unwrap_or_else(|| panic!("Resource {:?} has never been accepted as proposal fee", resource_address))
+        .vault.take_all()
+}
+
+// regular_price is the price of the paid resource, crumbs_price the global CRUMBS price
+pub(crate) fn price(
+    fee_override: Option<ProposalFeeOverride>,
+    regular_price: Decimal,
+    crumbs_price: Decimal,
+    is_crumbs: bool,
+    proposal_count: u64,
+) -> Decimal {
+    match fee_override {
+        None => regular_price,
+        Some(ProposalFeeOverride::Free) => Decimal::zero(),
+        Some(ProposalFeeOverride::FreeProposals(free_proposals)) if proposal_count < free_proposals => Decimal::zero(),
+        Some(ProposalFeeOverride::FreeProposals(_)) => regular_price,
+        Some(ProposalFeeOverride::Discount(discount)) => regular_price * (dec!("100") - discount) / dec!("100"),
+        Some(ProposalFeeOverride::Price(price)) if is_crumbs => price,
+        Some(ProposalFeeOverride::Price(_)) if crumbs_price.is_zero() => regular_price,
+        Some(ProposalFeeOverride::Price(price)) => regular_price * price / crumbs_price,
+    }
+}
@@ -3,6 +3,7 @@ use scrypto::prelude::*;
 use crate::proposal_result_option::proposal_result_option::ProposalResultOption;
 use crate::proposal_vote::proposal_vote::ProposalVote;
 use crate::proposal_option::proposal_option::ProposalOption;
+use crate::views::{ProposalResultOptionView, ProposalResultView};
 
 #[blueprint]
 mod proposal_result {
@@ -27,6 +28,22 @@ mod proposal_result {
 
             result
         }
+
+        pub(crate) fn vote_count(&self) -> u32 {
+            self.vote_count
+        }
+
+        pub(crate) fn vote_power(&self) -> Decimal {
+            self.vote_power
+        }
+
+        pub(crate) fn view(&self) -> ProposalResultView {
+            ProposalResultView {
+                vote_count: self.vote_count,
+                vote_power: self.vote_power,
+                results: self.results.iter().map(|result_option| result_option.view()).collect(),
+            }
+        }
     }
 }
 
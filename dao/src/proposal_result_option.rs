@@ -1,5 +1,7 @@
 use scrypto::prelude::*;
 
+use crate::views::ProposalResultOptionView;
+
 #[blueprint]
 mod proposal_result_option {
     
@@ -23,5 +25,13 @@ mod proposal_result_option {
 
             result_option
         }
+
+        pub(crate) fn view(&self) -> ProposalResultOptionView {
+            ProposalResultOptionView {
+                option_id: self.option_id.clone(),
+                option_name: self.option_name.clone(),
+                share: self.share,
+            }
+        }
     }
 }
@@ -0,0 +1,80 @@
+use scrypto::prelude::*;
+
+use crate::proposal::Proposal;
+
+// voters have one week (5 minute epochs) after voting end to claim before the DAO can sweep the leftovers
+const CLAIM_PERIOD_EPOCHS: u64 = 2016;
+const CLAIM_PERIOD_DAYS: i64 = 7;
+
+#[derive(ScryptoSbor)]
+pub(crate) struct ProposalReward {
+    total: Decimal,
+    vault: Vault,
+    claimed_votes: HashSet<String>,
+    swept: bool,
+}
+
+pub(crate) fn fund(reward: Bucket) -> ProposalReward {
+    assert!(!reward.is_empty(), "Reward pool of proposal must not be empty");
+
+    let proposal_reward = ProposalReward {
+        total: reward.amount(),
+        vault: Vault::with_bucket(reward),
+        claimed_votes: HashSet::new(),
+        swept: false,
+    };
+
+    proposal_reward
+}
+
+pub(crate) fn claim(
+    proposal_reward: &mut ProposalReward,
+    proposal: &Proposal,
+    proposal_vote_id: &String,
+    voting_nfts: Proof,
+) -> Bucket {
+    assert!(!proposal_reward.swept, "Reward pool has already been swept");
+    assert!(!proposal.is_cancelled(), "Proposal has been cancelled");
+
+    assert!(proposal.has_voting_ended(), "Rewards can only be claimed after voting end {}", proposal.voting_end_label());
+    assert!(!proposal_reward.claimed_votes.contains(proposal_vote_id), "Reward for vote {} has already been claimed", proposal_vote_id);
+
+    let vote = proposal.votes().iter()
+        .find(|vote| vote.id() == *proposal_vote_id)
+        .unwrap_or_else(|| panic!("Vote with id {} does not exist", proposal_vote_id));
+
+    let nfts = voting_nfts.check_with_message(proposal.governance_resource(), "NFTs are not from governance resource").as_non_fungible().non_fungible_local_ids();
+    assert!(vote.nfts().iter().all(|nft| nfts.contains(nft)), "Provided NFTs are not the NFTs of vote {}", proposal_vote_id);
+
+    let reward_share = proposal_reward.total * vote.power() / proposal.votes_power();
+
+    proposal_reward.claimed_votes.insert(proposal_vote_id.clone());
+    proposal_reward.vault.take_advanced(reward_share, WithdrawStrategy::Rounded(RoundingMode::ToZero))
+}
+
+pub(crate) fn sweep(proposal_reward: &mut ProposalReward, proposal: &Proposal) -> Bucket {
+    assert!(!proposal_reward.swept, "Reward pool has already been swept");
+
+    proposal_reward.swept = true;
+    if proposal.is_cancelled() {
+        return proposal_reward.vault.take_all();
+    }
+
+    assert!(proposal.has_voting_ended(), "Reward pool can only be swept after voting end {}", proposal.voting_end_label());
+
+    let all_votes_claimed = proposal.votes().iter().all(|vote| proposal_reward.claimed_votes.contains(&vote.id()));
+    assert!(all_votes_claimed || has_claim_period_ended(proposal), "Reward pool can only be swept when all votes claimed or one week after voting end {}", proposal.voting_end_label());
+
+    proposal_reward.vault.take_all()
+}
+
+fn has_claim_period_ended(proposal: &Proposal) -> bool {
+    match proposal.voting_times() {
+        Some(voting_times) => {
+            let claim_period_end = voting_times.end.add_days(CLAIM_PERIOD_DAYS).expect("Claim period end overflows");
+            Clock::current_time_is_strictly_after(claim_period_end, TimePrecision::Minute)
+        }
+        None => proposal.voting_end_epoch()
+            .is_some_and(|voting_end_epoch| Runtime::current_epoch().number() > voting_end_epoch.number() + CLAIM_PERIOD_EPOCHS),
+    }
+}
@@ -0,0 +1,51 @@
+use scrypto::prelude::*;
+
+#[derive(ScryptoSbor)]
+pub(crate) struct ProposalSponsorship {
+    required_seconds: u32,
+    seconds: u32,
+    seconding_nfts: HashSet<NonFungibleLocalId>,
+}
+
+pub(crate) fn check_governance_holder(
+    governance_proof: Proof,
+    governance_resource: ResourceAddress,
+    threshold: Decimal,
+) -> CheckedProof {
+    let governance_proof_checked = governance_proof.check_with_message(governance_resource, "Proof is not from governance resource");
+    assert!(governance_proof_checked.amount() >= threshold, "At least {} of the governance resource are required. Provided {}", threshold, governance_proof_checked.amount());
+    governance_proof_checked
+}
+
+pub(crate) fn open(required_seconds: u32, proposer_proof: &CheckedProof) -> ProposalSponsorship {
+    // the proposer's own NFTs can not second the proposal
+    let seconding_nfts = if proposer_proof.resource_address().is_fungible() {
+        HashSet::new()
+    } else {
+        proposer_proof.as_non_fungible().non_fungible_local_ids().into_iter().collect()
+    };
+
+    let proposal_sponsorship = ProposalSponsorship {
+        required_seconds,
+        seconds: 0,
+        seconding_nfts,
+    };
+
+    proposal_sponsorship
+}
+
+// every governance NFT counts as one second, so splitting NFTs over several calls gains nothing.
+// returns true when the proposal has enough seconds to become active
+pub(crate) fn second(proposal_sponsorship: &mut ProposalSponsorship, member_proof: CheckedProof) -> bool {
+    assert!(proposal_sponsorship.required_seconds > 0, "Proposal can only be sponsored by a DAO admin");
+    assert!(!member_proof.resource_address().is_fungible(), "Seconding requires a non-fungible governance resource");
+
+    let nfts = member_proof.as_non_fungible().non_fungible_local_ids();
+    for nft in nfts.iter() {
+        assert!(!proposal_sponsorship.seconding_nfts.contains(nft), "NFT {} already seconded or proposed this proposal", nft.to_string());
+    }
+    proposal_sponsorship.seconds += nfts.len() as u32;
+    proposal_sponsorship.seconding_nfts.extend(nfts.into_iter());
+
+    proposal_sponsorship.seconds >= proposal_sponsorship.required_seconds
+}
@@ -1,7 +1,9 @@
 use scrypto::prelude::*;
 
+use crate::validation;
+
 pub(crate) fn create_non_fungible_id_of_uuid(uuid: &str) -> NonFungibleLocalId {
+    validation::check_uuid("Id", uuid);
     let nft_id = uuid.replace("-", "_");
-    let non_fungible_id = NonFungibleLocalId::string(nft_id);
-    non_fungible_id.unwrap()
-}
\ No newline at end of file
+    NonFungibleLocalId::string(nft_id).unwrap_or_else(|_| panic!("Id {} can not be used as NFT id", uuid))
+}
@@ -0,0 +1,158 @@
+use scrypto::prelude::*;
+
+use crate::dao::DaoPatch;
+
+const MAX_NAME_LENGTH: usize = 100;
+const MAX_TITLE_LENGTH: usize = 200;
+const MAX_URL_LENGTH: usize = 500;
+const MAX_TIMESTAMP_LENGTH: usize = 64;
+const MAX_ABOUT_LENGTH: usize = 2_000;
+const MAX_ABSTRACT_LENGTH: usize = 2_000;
+const MAX_OPTION_LENGTH: usize = 500;
+const MAX_GENERAL_LENGTH: usize = 10_000;
+const MAX_SPECIFICATION_LENGTH: usize = 20_000;
+const MAX_RULES: usize = 50;
+const MAX_RULE_LENGTH: usize = 1_000;
+const MAX_ADDITIONAL_DATA_ENTRIES: usize = 50;
+const MAX_ADDITIONAL_DATA_KEY_LENGTH: usize = 100;
+const MAX_ADDITIONAL_DATA_VALUE_LENGTH: usize = 2_000;
+const MAX_ADDITIONAL_DATA_VEC_VALUES: usize = 100;
+const URL_SCHEMES: [&str; 1] = ["https://"];
+
+// ids are lowercase or uppercase hex UUIDs like 9ca67daa-2f84-4db2-aec3-8deaa2bdd093
+pub(crate) fn check_uuid(field: &str, value: &str) {
+    let is_uuid = value.len() == 36 && value.char_indices().all(|(index, c)| match index {
+        8 | 13 | 18 | 23 => c == '-',
+        _ => c.is_ascii_hexdigit(),
+    });
+    assert!(is_uuid, "{} {} is not a valid UUID", field, value);
+}
+
+pub(crate) fn check_url(field: &str, value: &str) {
+    check_length(field, value, MAX_URL_LENGTH);
+    assert!(URL_SCHEMES.iter().any(|scheme| value.starts_with(scheme) && value.len() > scheme.len()), "{} {} must be an URL starting with one of {:?}", field, value, URL_SCHEMES);
+}
+
+pub(crate) fn check_length(field: &str, value: &str, max_length: usize) {
+    let length = value.chars().count();
+    assert!(length <= max_length, "{} is too long: {} characters, at most {} allowed", field, length, max_length);
+}
+
+pub(crate) fn check_not_empty(field: &str, value: &str) {
+    assert!(!value.trim().is_empty(), "{} must not be empty", field);
+}
+
+pub(crate) fn check_rules(rules: &[String]) {
+    assert!(rules.len() <= MAX_RULES, "Too many rules: {}, at most {} allowed", rules.len(), MAX_RULES);
+    for rule in rules {
+        check_length("Rule", rule, MAX_RULE_LENGTH);
+    }
+}
+
+pub(crate) fn check_additional_data(additional_data: &HashMap<String, String>) {
+    assert!(additional_data.len() <= MAX_ADDITIONAL_DATA_ENTRIES, "Too many additional data entries: {}, at most {} allowed", additional_data.len(), MAX_ADDITIONAL_DATA_ENTRIES);
+    for (key, value) in additional_data {
+        check_not_empty("Additional data key", key);
+        check_length("Additional data key", key, MAX_ADDITIONAL_DATA_KEY_LENGTH);
+        check_length(&format!("Additional data value of {}", key), value, MAX_ADDITIONAL_DATA_VALUE_LENGTH);
+    }
+}
+
+pub(crate) fn check_additional_data_vec(additional_data_vec: &HashMap<String, Vec<String>>) {
+    assert!(additional_data_vec.len() <= MAX_ADDITIONAL_DATA_ENTRIES, "Too many additional data vec entries: {}, at most {} allowed", additional_data_vec.len(), MAX_ADDITIONAL_DATA_ENTRIES);
+    for (key, values) in additional_data_vec {
+        check_not_empty("Additional data vec key", key);
+        check_length("Additional data vec key", key, MAX_ADDITIONAL_DATA_KEY_LENGTH);
+        assert!(values.len() <= MAX_ADDITIONAL_DATA_VEC_VALUES, "Too many values for additional data vec key {}: {}, at most {} allowed", key, values.len(), MAX_ADDITIONAL_DATA_VEC_VALUES);
+        for value in values {
+            check_length(&format!("Additional data vec value of {}", key), value, MAX_ADDITIONAL_DATA_VALUE_LENGTH);
+        }
+    }
+}
+
+pub(crate) fn check_dao(
+    name: &str,
+    info_url: &str,
+    key_image_url: &str,
+    about: &str,
+    general: &str,
+    rules: &[String],
+    additional_data: &HashMap<String, String>,
+) {
+    check_not_empty("DAO name", name);
+    check_length("DAO name", name, MAX_NAME_LENGTH);
+    check_url("Info URL", info_url);
+    check_url("Key image URL", key_image_url);
+    check_length("About", about, MAX_ABOUT_LENGTH);
+    check_length("General", general, MAX_GENERAL_LENGTH);
+    check_rules(rules);
+    check_additional_data(additional_data);
+}
+
+pub(crate) fn check_dao_patch(patch: &DaoPatch) {
+    if let Some(name) = &patch.name {
+        check_not_empty("DAO name", name);
+        check_length("DAO name", name, MAX_NAME_LENGTH);
+    }
+    if let Some(info_url) = &patch.info_url {
+        check_url("Info URL", info_url);
+    }
+    if let Some(key_image_url) = &patch.key_image_url {
+        check_url("Key image URL", key_image_url);
+    }
+    if let Some(about) = &patch.about {
+        check_length("About", about, MAX_ABOUT_LENGTH);
+    }
+    if let Some(general) = &patch.general {
+        check_length("General", general, MAX_GENERAL_LENGTH);
+    }
+    if let Some(rules) = &patch.rules {
+        check_rules(rules);
+    }
+    if let Some(additional_data) = &patch.additional_data {
+        check_additional_data(additional_data);
+    }
+}
+
+pub(crate) fn check_proposal(
+    proposal_id: &str,
+    title: &str,
+    proposal_abstract: &str,
+    specification: &str,
+    voting_start: &str,
+    voting_end: &str,
+    created: &str,
+    info_url: &str,
+    additional_data: &HashMap<String, String>,
+) {
+    check_uuid("Proposal id", proposal_id);
+    check_not_empty("Proposal title", title);
+    check_length("Proposal title", title, MAX_TITLE_LENGTH);
+    check_length("Proposal abstract", proposal_abstract, MAX_ABSTRACT_LENGTH);
+    check_length("Specification", specification, MAX_SPECIFICATION_LENGTH);
+    check_timestamp("Voting start", voting_start);
+    check_timestamp("Voting end", voting_end);
+    check_timestamp("Created", created);
+    check_url("Info URL", info_url);
+    check_additional_data(additional_data);
+}
+
+pub(crate) fn check_option(proposal_id: &str, proposal_option_id: &str, option: &str, additional_data: &HashMap<String, String>) {
+    check_uuid("Proposal id", proposal_id);
+    check_uuid("Proposal option id", proposal_option_id);
+    check_not_empty("Option", option);
+    check_length("Option", option, MAX_OPTION_LENGTH);
+    check_additional_data(additional_data);
+}
+
+pub(crate) fn check_vote(proposal_id: &str, proposal_vote_id: &str, proposal_option_id: &str, created: &str, additional_data: &HashMap<String, String>) {
+    check_uuid("Proposal id", proposal_id);
+    check_uuid("Proposal vote id", proposal_vote_id);
+    check_uuid("Proposal option id", proposal_option_id);
+    check_timestamp("Created", created);
+    check_additional_data(additional_data);
+}
+
+pub(crate) fn check_timestamp(field: &str, value: &str) {
+    check_length(field, value, MAX_TIMESTAMP_LENGTH);
+}
@@ -0,0 +1,73 @@
+use scrypto::prelude::*;
+
+use crate::dao::{DaoStatus, DaoType};
+use crate::proposal::{ProposalStatus, VotingTimes};
+
+#[derive(ScryptoSbor, Clone, Debug)]
+pub struct DaoView {
+    pub dao_id: String,
+    pub version: u32,
+    pub revision: u64,
+    pub updated_epoch: Option<Epoch>,
+    pub name: String,
+    pub description: String,
+    pub info_url: Url,
+    pub key_image_url: Url,
+    pub dao_type: DaoType,
+    pub governance_resource: ResourceAddress,
+    pub about: String,
+    pub general: String,
+    pub created: String,
+    pub created_epoch: Epoch,
+    pub rules: Vec<String>,
+    pub additional_data: HashMap<String, String>,
+    pub additional_data_vec: HashMap<String, Vec<String>>,
+    pub proposals: ResourceAddress,
+    pub status: DaoStatus,
+    pub frozen: bool,
+}
+
+#[derive(ScryptoSbor, Clone, Debug)]
+pub struct ProposalOptionView {
+    pub option_id: String,
+    pub rank: u32,
+    pub option: String,
+}
+
+#[derive(ScryptoSbor, Clone, Debug)]
+pub struct ProposalView {
+    pub proposal_id: String,
+    pub version: u32,
+    pub title: String,
+    pub proposal_abstract: String,
+    pub specification: String,
+    pub governance_resource: ResourceAddress,
+    pub voting_start: String,
+    pub voting_start_epoch: Option<Epoch>,
+    pub voting_end: String,
+    pub voting_end_epoch: Option<Epoch>,
+    pub voting_times: Option<VotingTimes>,
+    pub created: String,
+    pub created_epoch: Epoch,
+    pub info_url: Url,
+    pub key_image_url: Url,
+    pub additional_data: HashMap<String, String>,
+    pub additional_data_vec: HashMap<String, Vec<String>>,
+    pub status: ProposalStatus,
+    pub options: Vec<ProposalOptionView>,
+    pub vote_count: u32,
+}
+
+#[derive(ScryptoSbor, Clone, Debug)]
+pub struct ProposalResultOptionView {
+    pub option_id: String,
+    pub option_name: String,
+    pub share: Decimal,
+}
+
+#[derive(ScryptoSbor, Clone, Debug)]
+pub struct ProposalResultView {
+    pub vote_count: u32,
+    pub vote_power: Decimal,
+    pub results: Vec<ProposalResultOptionView>,
+}
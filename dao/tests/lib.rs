@@ -1,9 +1,91 @@
+use crumbsup_dao::additional_data::AdditionalDataChange;
+use crumbsup_dao::dao::{DaoPatch, DaoStatus, DaoType};
+use crumbsup_dao::dao_admin_badge::DaoAdminRole;
+use crumbsup_dao::dao_operation::DaoOperation;
+use crumbsup_dao::dao_settings::DepositSlashTarget;
+use crumbsup_dao::fee_distribution::FeeDistribution;
+use crumbsup_dao::proposal::{ProposalStatus, VotingTimes};
+use crumbsup_dao::proposal_fee::ProposalFeeOverride;
 use crumbsup_dao::test_bindings::DaoHoard;
 use radix_engine_interface::prelude::*;
 use scrypto::this_package;
 use scrypto_test::prelude::*;
 use scrypto_unit::*;
 
+const DAO_ID: &str = "9ca67daa-2f84-4db2-aec3-8deaa2bdd093";
+const PROPOSAL_ID: &str = "3f1f4a52-5b8e-4c53-9d6a-0c1d2e3f4a5b";
+const OPTION_ID: &str = "7a2b3c4d-1e2f-4a5b-8c9d-0e1f2a3b4c5d";
+const FIRST_VOTE_ID: &str = "11111111-2222-4333-8444-555555555555";
+const SECOND_VOTE_ID: &str = "66666666-7777-4888-9999-aaaaaaaaaaaa";
+
+#[derive(ScryptoSbor, NonFungibleData)]
+struct GovernanceNft {
+    name: String,
+}
+
+// mirrors the data of a DAO admin badge to read it back from the badge resource
+#[derive(ScryptoSbor)]
+struct AdminBadgeData {
+    dao_id: String,
+    role: DaoAdminRole,
+    expires_epoch: Option<Epoch>,
+    name: String,
+    description: String,
+    info_url: Url,
+    key_image_url: Url,
+    additional_data: HashMap<String, String>,
+    additional_data_vec: HashMap<String, Vec<String>>,
+}
+
+struct TestDao {
+    dao_hoard: DaoHoard,
+    owner_badge: Bucket,
+    admin_badge: Bucket,
+    governance_nfts: Bucket,
+    token_address: ResourceAddress,
+}
+
+// a DAO Hoard with one NFT governed DAO, its owner admin badge and three governance NFTs
+fn create_test_dao(env: &mut TestEnvironment) -> Result<TestDao, RuntimeError> {
+    let package_address = Package::compile_and_publish(this_package!(), env)?;
+    let token_address = ResourceAddress::try_from_hex(
+        "5da66318c6318c61f5a61b4c6318c6318cf794aa8d295f14e6318c6318c6",
+    )
+    .unwrap();
+    let owner_badge = ResourceBuilder::new_fungible(OwnerRole::None)
+        .divisibility(DIVISIBILITY_NONE)
+        .mint_initial_supply(1, env)?;
+    let owner_badge_address = owner_badge.resource_address(env)?;
+    let mut dao_hoard =
+        DaoHoard::dao_hoard_instantiate(owner_badge_address, token_address, dec!("15"), false, package_address, env)?;
+    let governance_nfts = ResourceBuilder::new_integer_non_fungible::<GovernanceNft>(OwnerRole::None)
+        .mint_initial_supply([
+            (1u64.into(), GovernanceNft { name: "member 1".to_string() }),
+            (2u64.into(), GovernanceNft { name: "member 2".to_string() }),
+            (3u64.into(), GovernanceNft { name: "member 3".to_string() }),
+        ], env)?;
+    let governance_address = governance_nfts.resource_address(env)?;
+
+    let payment = BucketFactory::create_fungible_bucket(token_address, dec!("0"), CreationStrategy::Mock, env)?;
+    let (_dao, admin_badge, _change) = dao_hoard.dao_create(
+        payment,
+        DAO_ID.to_string(),
+        "dao name".to_string(),
+        "https://info.url".to_string(),
+        "https://logo.url".to_string(),
+        DaoType::NftCollection,
+        governance_address,
+        "dao about".to_string(),
+        "dao general".to_string(),
+        "2024-01-26T22:16:32.256163Z".to_string(),
+        vec!["rule 1".to_string()],
+        HashMap::new(),
+        env,
+    )?;
+
+    Ok(TestDao { dao_hoard, owner_badge, admin_badge, governance_nfts, token_address })
+}
+
 #[test]
 fn test_create_dao_hoard() {
     let mut test_runner = TestRunnerBuilder::new().build();
@@ -12,6 +94,8 @@ fn test_create_dao_hoard() {
         "5da66318c6318c61f5a61b4c6318c6318cf794aa8d295f14e6318c6318c6",
     )
     .unwrap();
+    let (_public_key, _private_key, account) = test_runner.new_allocated_account();
+    let owner_badge_address = test_runner.create_fungible_resource(dec!("1"), 0, account);
 
     let manifest = ManifestBuilder::new()
         .call_function(
@@ -19,8 +103,10 @@ fn test_create_dao_hoard() {
             "DaoHoard",
             "dao_hoard_instantiate",
             manifest_args!(
+                owner_badge_address,
                 token_address,
-                dec!("234")
+                dec!("234"),
+                false
             ),
         )
         .build();
@@ -38,8 +124,12 @@ fn test_dao_instantiate() -> Result<(), RuntimeError> {
         "5da66318c6318c61f5a61b4c6318c6318cf794aa8d295f14e6318c6318c6",
     )
     .unwrap();
+    let owner_badge = ResourceBuilder::new_fungible(OwnerRole::None)
+        .divisibility(DIVISIBILITY_NONE)
+        .mint_initial_supply(1, &mut env)?;
+    let owner_badge_address = owner_badge.resource_address(&mut env)?;
     let mut dao_hoard =
-        DaoHoard::dao_hoard_instantiate(token_address, dec!("15"), package_address, &mut env)?;
+        DaoHoard::dao_hoard_instantiate(owner_badge_address, token_address, dec!("15"), false, package_address, &mut env)?;
 
     // Act
     let rules: Vec<String> = vec![
@@ -52,13 +142,15 @@ fn test_dao_instantiate() -> Result<(), RuntimeError> {
         ("add_key_2".to_string(), "add_value_2".to_string()),
     ]);
 
+    let payment = BucketFactory::create_fungible_bucket(token_address, dec!("0"), CreationStrategy::Mock, &mut env)?;
     let _ = dao_hoard.dao_create(
+        payment,
         "9ca67daa-2f84-4db2-aec3-8deaa2bdd093".to_string(),
         "dao name".to_string(),
         "https://info.url".to_string(),
         "https://logo.url".to_string(),
-        "token".to_string(),
-        "resource_address".to_string(),
+        DaoType::FungibleToken,
+        token_address,
         "dao about".to_string(),
         "dao general".to_string(),
         "2024-01-26T22:16:32.256163Z".to_string(),
@@ -68,6 +160,1013 @@ fn test_dao_instantiate() -> Result<(), RuntimeError> {
     )?;
 
     // Assert
+    let dao = dao_hoard.dao_get("9ca67daa-2f84-4db2-aec3-8deaa2bdd093".to_string(), &mut env)?;
+    assert_eq!(dao.name, "dao name");
+    assert_eq!(dao.governance_resource, token_address);
+    assert_eq!(dao.rules.len(), 3);
+
+    let proposal_ids = dao_hoard.dao_list_proposals("9ca67daa-2f84-4db2-aec3-8deaa2bdd093".to_string(), 0, 10, &mut env)?;
+    assert!(proposal_ids.is_empty());
+
+    assert_eq!(dao_hoard.dao_count(&mut env)?, 1);
+    let entry = dao_hoard.dao_find_by_slug("dao-name".to_string(), &mut env)?.unwrap();
+    assert_eq!(entry.dao_id, "9ca67daa-2f84-4db2-aec3-8deaa2bdd093");
+
+    Ok(())
+}
+
+#[test]
+fn test_owner_badge_controls_dao_hoard() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let package_address = Package::compile_and_publish(this_package!(), &mut env)?;
+    let token_address = ResourceAddress::try_from_hex(
+        "5da66318c6318c61f5a61b4c6318c6318cf794aa8d295f14e6318c6318c6",
+    )
+    .unwrap();
+    let owner_badge = ResourceBuilder::new_fungible(OwnerRole::None)
+        .divisibility(DIVISIBILITY_NONE)
+        .mint_initial_supply(1, &mut env)?;
+    let owner_badge_address = owner_badge.resource_address(&mut env)?;
+    let mut dao_hoard =
+        DaoHoard::dao_hoard_instantiate(owner_badge_address, token_address, dec!("15"), false, package_address, &mut env)?;
+
+    // Act
+    let owner_proof = owner_badge.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+    dao_hoard.dao_hoard_set_proposal_creation_price(dec!("20"), &mut env)?;
+
+    // Assert
+    let price = dao_hoard.dao_get_proposal_price("9ca67daa-2f84-4db2-aec3-8deaa2bdd093".to_string(), token_address, &mut env)?;
+    assert_eq!(price, dec!("20"));
+
+    Ok(())
+}
+
+#[test]
+fn test_operation_executes_at_approval_threshold_and_expires() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let mut dao = create_test_dao(&mut env)?;
+    LocalAuthZone::push(dao.owner_badge.create_proof_of_all(&mut env)?, &mut env)?;
+    LocalAuthZone::push(dao.admin_badge.create_proof_of_all(&mut env)?, &mut env)?;
+    let second_admin_badge = dao.dao_hoard.dao_mint_admin_badge_by_owner(DAO_ID.to_string(), DaoAdminRole::Owner, None, &mut env)?;
+    dao.dao_hoard.dao_submit_operation(
+        dao.admin_badge.create_proof_of_all(&mut env)?,
+        DAO_ID.to_string(),
+        DaoOperation::SetApprovalPolicy { approval_threshold: 2, approval_expiry_epochs: 10 },
+        &mut env,
+    )?;
+
+    // Act
+    let rename = DaoOperation::UpdateDao {
+        patch: DaoPatch { name: Some("renamed dao".to_string()), ..Default::default() },
+    };
+    let (operation_id, _) = dao.dao_hoard.dao_submit_operation(dao.admin_badge.create_proof_of_all(&mut env)?, DAO_ID.to_string(), rename.clone(), &mut env)?;
+    let dao_before_approval = dao.dao_hoard.dao_get(DAO_ID.to_string(), &mut env)?;
+    dao.dao_hoard.dao_approve_operation(second_admin_badge.create_proof_of_all(&mut env)?, DAO_ID.to_string(), operation_id, &mut env)?;
+    let dao_after_approval = dao.dao_hoard.dao_get(DAO_ID.to_string(), &mut env)?;
+
+    let (expiring_operation_id, _) = dao.dao_hoard.dao_submit_operation(dao.admin_badge.create_proof_of_all(&mut env)?, DAO_ID.to_string(), rename, &mut env)?;
+    let current_epoch = env.get_current_epoch();
+    env.set_current_epoch(Epoch::of(current_epoch.number() + 11));
+    let late_approval = dao.dao_hoard.dao_approve_operation(second_admin_badge.create_proof_of_all(&mut env)?, DAO_ID.to_string(), expiring_operation_id, &mut env);
+
+    // Assert
+    assert_eq!(dao_before_approval.name, "dao name");
+    assert_eq!(dao_after_approval.name, "renamed dao");
+    assert!(late_approval.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_dao_admin_without_permission_is_denied() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let mut dao = create_test_dao(&mut env)?;
+    LocalAuthZone::push(dao.owner_badge.create_proof_of_all(&mut env)?, &mut env)?;
+    let moderator_badge = dao.dao_hoard.dao_mint_admin_badge_by_owner(DAO_ID.to_string(), DaoAdminRole::Moderator, None, &mut env)?;
+    LocalAuthZone::push(moderator_badge.create_proof_of_all(&mut env)?, &mut env)?;
+
+    // Act
+    let patch = DaoPatch { name: Some("renamed dao".to_string()), ..Default::default() };
+    let result = dao.dao_hoard.dao_patch(moderator_badge.create_proof_of_all(&mut env)?, DAO_ID.to_string(), patch, &mut env);
+
+    // Assert
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_reward_is_claimed_pro_rata_and_leftovers_are_swept() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let mut dao = create_test_dao(&mut env)?;
+    let (single_nft, voting_end_epoch) = add_rewarded_proposal_with_votes(&mut dao, &mut env)?;
+
+    // Act
+    env.set_current_epoch(Epoch::of(voting_end_epoch + 1));
+    let reward_share = dao.dao_hoard.proposal_claim_reward(DAO_ID.to_string(), PROPOSAL_ID.to_string(), FIRST_VOTE_ID.to_string(), single_nft.create_proof_of_all(&mut env)?, &mut env)?;
+
+    // the second vote never claims, so the pool is swept once the claim period is over
+    env.set_current_epoch(Epoch::of(voting_end_epoch + 2016 + 1));
+    dao.dao_hoard.proposal_sweep_reward(dao.admin_badge.create_proof_of_all(&mut env)?, DAO_ID.to_string(), PROPOSAL_ID.to_string(), &mut env)?;
+    let treasury = dao.dao_hoard.dao_withdraw_treasury(dao.admin_badge.create_proof_of_all(&mut env)?, DAO_ID.to_string(), dao.token_address, &mut env)?;
+
+    // Assert
+    assert_eq!(reward_share.amount(&mut env)?, dec!("30"));
+    assert_eq!(treasury.amount(&mut env)?, dec!("60"));
+
+    Ok(())
+}
+
+#[test]
+fn test_reward_can_not_be_claimed_twice() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let mut dao = create_test_dao(&mut env)?;
+    let (single_nft, voting_end_epoch) = add_rewarded_proposal_with_votes(&mut dao, &mut env)?;
+    env.set_current_epoch(Epoch::of(voting_end_epoch + 1));
+    dao.dao_hoard.proposal_claim_reward(DAO_ID.to_string(), PROPOSAL_ID.to_string(), FIRST_VOTE_ID.to_string(), single_nft.create_proof_of_all(&mut env)?, &mut env)?;
+
+    // Act
+    let second_claim = dao.dao_hoard.proposal_claim_reward(DAO_ID.to_string(), PROPOSAL_ID.to_string(), FIRST_VOTE_ID.to_string(), single_nft.create_proof_of_all(&mut env)?, &mut env);
+
+    // Assert
+    assert!(second_claim.is_err());
+
+    Ok(())
+}
+
+// a proposal with a reward pool of 90, voted once with one governance NFT and once with the other two.
+// returns the single NFT and the voting end epoch
+fn add_rewarded_proposal_with_votes(dao: &mut TestDao, env: &mut TestEnvironment) -> Result<(Bucket, u64), RuntimeError> {
+    LocalAuthZone::push(dao.admin_badge.create_proof_of_all(env)?, env)?;
+    let voting_start_epoch = env.get_current_epoch().number() + 1;
+    let voting_end_epoch = voting_start_epoch + 1;
+
+    let payment = BucketFactory::create_fungible_bucket(dao.token_address, dec!("15"), CreationStrategy::Mock, env)?;
+    let reward = BucketFactory::create_fungible_bucket(dao.token_address, dec!("90"), CreationStrategy::Mock, env)?;
+    dao.dao_hoard.dao_add_proposal(
+        dao.admin_badge.create_proof_of_all(env)?,
+        payment,
+        DAO_ID.to_string(),
+        PROPOSAL_ID.to_string(),
+        "proposal title".to_string(),
+        "proposal abstract".to_string(),
+        "proposal specification".to_string(),
+        "2024-02-01T00:00:00.000000Z".to_string(),
+        Some(voting_start_epoch),
+        "2024-02-02T00:00:00.000000Z".to_string(),
+        Some(voting_end_epoch),
+        None,
+        "2024-01-26T22:16:32.256163Z".to_string(),
+        "https://info.url".to_string(),
+        None,
+        HashMap::new(),
+        Some(reward),
+        env,
+    )?;
+    dao.dao_hoard.proposal_add_option(
+        dao.admin_badge.create_proof_of_all(env)?,
+        DAO_ID.to_string(),
+        PROPOSAL_ID.to_string(),
+        OPTION_ID.to_string(),
+        1,
+        "yes".to_string(),
+        HashMap::new(),
+        env,
+    )?;
+
+    env.set_current_epoch(Epoch::of(voting_start_epoch));
+    let single_nft = dao.governance_nfts.take(dec!("1"), env)?;
+    for (proposal_vote_id, voting_nfts) in [(FIRST_VOTE_ID, &single_nft), (SECOND_VOTE_ID, &dao.governance_nfts)] {
+        dao.dao_hoard.proposal_mint_nft_vote(
+            DAO_ID.to_string(),
+            PROPOSAL_ID.to_string(),
+            proposal_vote_id.to_string(),
+            OPTION_ID.to_string(),
+            FAUCET,
+            voting_nfts.create_proof_of_all(env)?,
+            "2024-02-01T12:00:00.000000Z".to_string(),
+            HashMap::new(),
+            env,
+        )?;
+    }
+
+    Ok((single_nft, voting_end_epoch))
+}
+
+#[test]
+fn test_migration_skips_daos_and_proposals_of_the_current_version() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let mut dao = create_test_dao(&mut env)?;
+    add_rewarded_proposal_with_votes(&mut dao, &mut env)?;
+    LocalAuthZone::push(dao.owner_badge.create_proof_of_all(&mut env)?, &mut env)?;
+
+    // Act
+    let migrated_dao_ids = dao.dao_hoard.dao_hoard_migrate_daos(0, 10, &mut env)?;
+    let migrated_proposal_ids = dao.dao_hoard.dao_hoard_migrate_proposals(DAO_ID.to_string(), 0, 10, &mut env)?;
+
+    // Assert
+    assert!(migrated_dao_ids.is_empty());
+    assert!(migrated_proposal_ids.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn test_dao_type_must_match_governance_resource() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let mut dao = create_test_dao(&mut env)?;
+
+    // Act
+    let payment = BucketFactory::create_fungible_bucket(dao.token_address, dec!("0"), CreationStrategy::Mock, &mut env)?;
+    let result = dao.dao_hoard.dao_create(
+        payment,
+        "0b5d8a3e-6c1f-4e2a-9b7d-3f4e5a6b7c8d".to_string(),
+        "token dao".to_string(),
+        "https://info.url".to_string(),
+        "https://logo.url".to_string(),
+        DaoType::NftCollection,
+        dao.token_address,
+        "dao about".to_string(),
+        "dao general".to_string(),
+        "2024-01-26T22:16:32.256163Z".to_string(),
+        vec![],
+        HashMap::new(),
+        &mut env,
+    );
+
+    // Assert
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_fungible_dao_rejects_nft_voted_proposals() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let mut dao = create_test_dao(&mut env)?;
+    let fungible_dao_id = "0b5d8a3e-6c1f-4e2a-9b7d-3f4e5a6b7c8d";
+    let payment = BucketFactory::create_fungible_bucket(dao.token_address, dec!("0"), CreationStrategy::Mock, &mut env)?;
+    let (_dao, fungible_dao_admin_badge, _change) = dao.dao_hoard.dao_create(
+        payment,
+        fungible_dao_id.to_string(),
+        "token dao".to_string(),
+        "https://info.url".to_string(),
+        "https://logo.url".to_string(),
+        DaoType::FungibleToken,
+        dao.token_address,
+        "dao about".to_string(),
+        "dao general".to_string(),
+        "2024-01-26T22:16:32.256163Z".to_string(),
+        vec![],
+        HashMap::new(),
+        &mut env,
+    )?;
+    LocalAuthZone::push(fungible_dao_admin_badge.create_proof_of_all(&mut env)?, &mut env)?;
+    let voting_start_epoch = env.get_current_epoch().number() + 1;
+
+    // Act
+    let payment = BucketFactory::create_fungible_bucket(dao.token_address, dec!("15"), CreationStrategy::Mock, &mut env)?;
+    let result = dao.dao_hoard.dao_add_proposal(
+        fungible_dao_admin_badge.create_proof_of_all(&mut env)?,
+        payment,
+        fungible_dao_id.to_string(),
+        PROPOSAL_ID.to_string(),
+        "proposal title".to_string(),
+        "proposal abstract".to_string(),
+        "proposal specification".to_string(),
+        "2024-02-01T00:00:00.000000Z".to_string(),
+        Some(voting_start_epoch),
+        "2024-02-02T00:00:00.000000Z".to_string(),
+        Some(voting_start_epoch + 1),
+        None,
+        "2024-01-26T22:16:32.256163Z".to_string(),
+        "https://info.url".to_string(),
+        None,
+        HashMap::new(),
+        None,
+        &mut env,
+    );
+
+    // Assert
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_archive_is_approved_like_other_operations() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let mut dao = create_test_dao(&mut env)?;
+    LocalAuthZone::push(dao.owner_badge.create_proof_of_all(&mut env)?, &mut env)?;
+    LocalAuthZone::push(dao.admin_badge.create_proof_of_all(&mut env)?, &mut env)?;
+    let second_admin_badge = dao.dao_hoard.dao_mint_admin_badge_by_owner(DAO_ID.to_string(), DaoAdminRole::Owner, None, &mut env)?;
+    dao.dao_hoard.dao_submit_operation(
+        dao.admin_badge.create_proof_of_all(&mut env)?,
+        DAO_ID.to_string(),
+        DaoOperation::SetApprovalPolicy { approval_threshold: 2, approval_expiry_epochs: 10 },
+        &mut env,
+    )?;
+
+    // Act
+    let (operation_id, _) = dao.dao_hoard.dao_submit_operation(
+        dao.admin_badge.create_proof_of_all(&mut env)?,
+        DAO_ID.to_string(),
+        DaoOperation::SetStatus { status: DaoStatus::Archived },
+        &mut env,
+    )?;
+    dao.dao_hoard.dao_approve_operation(second_admin_badge.create_proof_of_all(&mut env)?, DAO_ID.to_string(), operation_id, &mut env)?;
+
+    // Assert
+    let dao_view = dao.dao_hoard.dao_get(DAO_ID.to_string(), &mut env)?;
+    assert_eq!(dao_view.status, DaoStatus::Archived);
+
+    Ok(())
+}
+
+#[test]
+fn test_unfreeze_restores_the_status_before_the_freeze() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let mut dao = create_test_dao(&mut env)?;
+    LocalAuthZone::push(dao.owner_badge.create_proof_of_all(&mut env)?, &mut env)?;
+    LocalAuthZone::push(dao.admin_badge.create_proof_of_all(&mut env)?, &mut env)?;
+    dao.dao_hoard.dao_archive(dao.admin_badge.create_proof_of_all(&mut env)?, DAO_ID.to_string(), &mut env)?;
+
+    // Act
+    dao.dao_hoard.dao_freeze(DAO_ID.to_string(), &mut env)?;
+    let frozen_dao = dao.dao_hoard.dao_get(DAO_ID.to_string(), &mut env)?;
+    dao.dao_hoard.dao_unfreeze(DAO_ID.to_string(), &mut env)?;
+    let unfrozen_dao = dao.dao_hoard.dao_get(DAO_ID.to_string(), &mut env)?;
+
+    // Assert
+    assert!(frozen_dao.frozen);
+    assert!(!unfrozen_dao.frozen);
+    assert_eq!(unfrozen_dao.status, DaoStatus::Archived);
+
+    Ok(())
+}
+
+#[test]
+fn test_frozen_dao_rejects_admin_changes() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let mut dao = create_test_dao(&mut env)?;
+    LocalAuthZone::push(dao.owner_badge.create_proof_of_all(&mut env)?, &mut env)?;
+    LocalAuthZone::push(dao.admin_badge.create_proof_of_all(&mut env)?, &mut env)?;
+    dao.dao_hoard.dao_freeze(DAO_ID.to_string(), &mut env)?;
+
+    // Act
+    let patch = DaoPatch { name: Some("renamed dao".to_string()), ..Default::default() };
+    let result = dao.dao_hoard.dao_patch(dao.admin_badge.create_proof_of_all(&mut env)?, DAO_ID.to_string(), patch, &mut env);
+
+    // Assert
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_single_admin_can_not_mint_through_operations() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let mut dao = create_test_dao(&mut env)?;
+    LocalAuthZone::push(dao.admin_badge.create_proof_of_all(&mut env)?, &mut env)?;
+
+    // Act
+    let result = dao.dao_hoard.dao_submit_operation(
+        dao.admin_badge.create_proof_of_all(&mut env)?,
+        DAO_ID.to_string(),
+        DaoOperation::MintAdminBadge { role: DaoAdminRole::Admin, expires_epoch: None },
+        &mut env,
+    );
+
+    // Assert
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_dao_creation_fee_is_taken_from_the_payment() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let mut dao = create_test_dao(&mut env)?;
+    LocalAuthZone::push(dao.owner_badge.create_proof_of_all(&mut env)?, &mut env)?;
+    dao.dao_hoard.dao_hoard_set_dao_creation_policy(dec!("10"), false, &mut env)?;
+
+    // Act
+    let payment = BucketFactory::create_fungible_bucket(dao.token_address, dec!("25"), CreationStrategy::Mock, &mut env)?;
+    let (_dao, _admin_badge, change) = dao.dao_hoard.dao_create(
+        payment,
+        "0b5d8a3e-6c1f-4e2a-9b7d-3f4e5a6b7c8d".to_string(),
+        "second dao".to_string(),
+        "https://info.url".to_string(),
+        "https://logo.url".to_string(),
+        DaoType::FungibleToken,
+        dao.token_address,
+        "dao about".to_string(),
+        "dao general".to_string(),
+        "2024-01-26T22:16:32.256163Z".to_string(),
+        vec![],
+        HashMap::new(),
+        &mut env,
+    )?;
+
+    // Assert
+    assert_eq!(change.amount(&mut env)?, dec!("15"));
+    assert_eq!(dao.dao_hoard.dao_count(&mut env)?, 2);
+
+    Ok(())
+}
+
+#[test]
+fn test_platform_creates_daos_while_approval_is_required() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let mut dao = create_test_dao(&mut env)?;
+    LocalAuthZone::push(dao.owner_badge.create_proof_of_all(&mut env)?, &mut env)?;
+    dao.dao_hoard.dao_hoard_set_dao_creation_policy(dec!("10"), true, &mut env)?;
+
+    // Act
+    dao.dao_hoard.dao_hoard_create_dao(
+        "0b5d8a3e-6c1f-4e2a-9b7d-3f4e5a6b7c8d".to_string(),
+        "platform dao".to_string(),
+        "https://info.url".to_string(),
+        "https://logo.url".to_string(),
+        DaoType::FungibleToken,
+        dao.token_address,
+        "dao about".to_string(),
+        "dao general".to_string(),
+        "2024-01-26T22:16:32.256163Z".to_string(),
+        vec![],
+        HashMap::new(),
+        &mut env,
+    )?;
+    let payment = BucketFactory::create_fungible_bucket(dao.token_address, dec!("10"), CreationStrategy::Mock, &mut env)?;
+    let direct_creation = dao.dao_hoard.dao_create(
+        payment,
+        "1c6e9b4f-7d2a-4f3b-8c8e-4a5f6b7c8d9e".to_string(),
+        "unapproved dao".to_string(),
+        "https://info.url".to_string(),
+        "https://logo.url".to_string(),
+        DaoType::FungibleToken,
+        dao.token_address,
+        "dao about".to_string(),
+        "dao general".to_string(),
+        "2024-01-26T22:16:32.256163Z".to_string(),
+        vec![],
+        HashMap::new(),
+        &mut env,
+    );
+
+    // Assert
+    assert!(direct_creation.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_proposal_is_scheduled_either_by_epochs_or_by_voting_times() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let mut dao = create_test_dao(&mut env)?;
+    LocalAuthZone::push(dao.admin_badge.create_proof_of_all(&mut env)?, &mut env)?;
+    let voting_start_epoch = env.get_current_epoch().number() + 1;
+    let voting_times = VotingTimes {
+        start: Instant::new(1_893_456_000),
+        end: Instant::new(1_893_542_400),
+    };
+
+    // Act
+    let payment = BucketFactory::create_fungible_bucket(dao.token_address, dec!("15"), CreationStrategy::Mock, &mut env)?;
+    add_scheduled_proposal(&mut dao, PROPOSAL_ID, payment, None, None, Some(voting_times), &mut env)?;
+    let payment = BucketFactory::create_fungible_bucket(dao.token_address, dec!("15"), CreationStrategy::Mock, &mut env)?;
+    let result = add_scheduled_proposal(
+        &mut dao,
+        "4b2c3d4e-5f6a-4b7c-8d9e-0f1a2b3c4d5e",
+        payment,
+        Some(voting_start_epoch),
+        Some(voting_start_epoch + 1),
+        Some(voting_times),
+        &mut env,
+    );
+
+    // Assert
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+fn add_scheduled_proposal(
+    dao: &mut TestDao,
+    proposal_id: &str,
+    payment: Bucket,
+    voting_start_epoch: Option<u64>,
+    voting_end_epoch: Option<u64>,
+    voting_times: Option<VotingTimes>,
+    env: &mut TestEnvironment,
+) -> Result<(Bucket, Bucket), RuntimeError> {
+    dao.dao_hoard.dao_add_proposal(
+        dao.admin_badge.create_proof_of_all(env)?,
+        payment,
+        DAO_ID.to_string(),
+        proposal_id.to_string(),
+        "proposal title".to_string(),
+        "proposal abstract".to_string(),
+        "proposal specification".to_string(),
+        "2030-01-01T00:00:00.000000Z".to_string(),
+        voting_start_epoch,
+        "2030-01-02T00:00:00.000000Z".to_string(),
+        voting_end_epoch,
+        voting_times,
+        "2024-01-26T22:16:32.256163Z".to_string(),
+        "https://info.url".to_string(),
+        None,
+        HashMap::new(),
+        None,
+        env,
+    )
+}
+
+#[test]
+fn test_distributed_fees_are_split_and_the_burn_share_is_burned() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let mut dao = create_test_dao(&mut env)?;
+    let fee_tokens = ResourceBuilder::new_fungible(OwnerRole::None)
+        .burn_roles(burn_roles! {
+            burner => rule!(allow_all);
+            burner_updater => rule!(deny_all);
+        })
+        .mint_initial_supply(10, &mut env)?;
+    let fee_address = fee_tokens.resource_address(&mut env)?;
+    LocalAuthZone::push(dao.owner_badge.create_proof_of_all(&mut env)?, &mut env)?;
+    dao.dao_hoard.dao_hoard_set_fee_resource(fee_address, Some(dec!("10")), &mut env)?;
+    dao.dao_hoard.dao_hoard_set_fee_distribution(
+        FeeDistribution {
+            platform_percentage: dec!("50"),
+            dao_treasury_percentage: dec!("20"),
+            burn_percentage: dec!("30"),
+        },
+        &mut env,
+    )?;
+    LocalAuthZone::push(dao.admin_badge.create_proof_of_all(&mut env)?, &mut env)?;
+    let voting_start_epoch = env.get_current_epoch().number() + 1;
+    add_scheduled_proposal(&mut dao, PROPOSAL_ID, fee_tokens, Some(voting_start_epoch), Some(voting_start_epoch + 1), None, &mut env)?;
+
+    // Act
+    dao.dao_hoard.dao_distribute_fees(DAO_ID.to_string(), &mut env)?;
+
+    // Assert
+    let record = dao.dao_hoard.dao_get_fee_record(DAO_ID.to_string(), &mut env)?;
+    assert_eq!(record.collected[&fee_address], dec!("10"));
+    assert_eq!(record.to_platform[&fee_address], dec!("5"));
+    assert_eq!(record.to_dao_treasury[&fee_address], dec!("2"));
+    assert_eq!(record.burned[&fee_address], dec!("3"));
+
+    Ok(())
+}
+
+#[test]
+fn test_proposal_listing_is_paged() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let mut dao = create_test_dao(&mut env)?;
+    add_rewarded_proposal_with_votes(&mut dao, &mut env)?;
+
+    // Act
+    let first_page = dao.dao_hoard.dao_list_proposals(DAO_ID.to_string(), 0, 100, &mut env)?;
+    let second_page = dao.dao_hoard.dao_list_proposals(DAO_ID.to_string(), 1, 100, &mut env)?;
+    let oversized_page = dao.dao_hoard.dao_list_proposals(DAO_ID.to_string(), 0, 101, &mut env);
+
+    // Assert
+    assert_eq!(first_page, vec![PROPOSAL_ID.to_string()]);
+    assert!(second_page.is_empty());
+    assert!(oversized_page.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_deposit_is_refunded_once_quorum_is_reached() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let mut dao = create_test_dao(&mut env)?;
+    let (proposal_nft, voting_start_epoch) = add_proposal_with_deposit(&mut dao, &mut env)?;
+    env.set_current_epoch(Epoch::of(voting_start_epoch));
+    cast_vote(&mut dao.dao_hoard, FIRST_VOTE_ID, &dao.governance_nfts, &mut env)?;
+    env.set_current_epoch(Epoch::of(voting_start_epoch + 2));
+
+    // Act
+    let refund = dao.dao_hoard.proposal_refund_deposit(proposal_nft.create_proof_of_all(&mut env)?, DAO_ID.to_string(), PROPOSAL_ID.to_string(), &mut env)?;
+    let second_refund = dao.dao_hoard.proposal_refund_deposit(proposal_nft.create_proof_of_all(&mut env)?, DAO_ID.to_string(), PROPOSAL_ID.to_string(), &mut env);
+
+    // Assert
+    assert_eq!(refund.amount(&mut env)?, dec!("20"));
+    assert!(second_refund.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_deposit_below_quorum_is_slashed_into_the_dao_treasury() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let mut dao = create_test_dao(&mut env)?;
+    let (_proposal_nft, voting_start_epoch) = add_proposal_with_deposit(&mut dao, &mut env)?;
+    env.set_current_epoch(Epoch::of(voting_start_epoch));
+    let single_nft = dao.governance_nfts.take(dec!("1"), &mut env)?;
+    cast_vote(&mut dao.dao_hoard, FIRST_VOTE_ID, &single_nft, &mut env)?;
+    env.set_current_epoch(Epoch::of(voting_start_epoch + 2));
+
+    // Act
+    dao.dao_hoard.proposal_slash_deposit(DAO_ID.to_string(), PROPOSAL_ID.to_string(), &mut env)?;
+    let treasury = dao.dao_hoard.dao_withdraw_treasury(dao.admin_badge.create_proof_of_all(&mut env)?, DAO_ID.to_string(), dao.token_address, &mut env)?;
+
+    // Assert
+    assert_eq!(treasury.amount(&mut env)?, dec!("20"));
+
+    Ok(())
+}
+
+// a proposal with a deposit of 20 and a quorum of 2 votes, returns the proposal NFT and the voting start epoch
+fn add_proposal_with_deposit(dao: &mut TestDao, env: &mut TestEnvironment) -> Result<(Bucket, u64), RuntimeError> {
+    LocalAuthZone::push(dao.admin_badge.create_proof_of_all(env)?, env)?;
+    dao.dao_hoard.dao_set_proposal_deposit(
+        dao.admin_badge.create_proof_of_all(env)?,
+        DAO_ID.to_string(),
+        dec!("20"),
+        dec!("2"),
+        DepositSlashTarget::DaoTreasury,
+        env,
+    )?;
+    let voting_start_epoch = env.get_current_epoch().number() + 1;
+
+    let payment = BucketFactory::create_fungible_bucket(dao.token_address, dec!("35"), CreationStrategy::Mock, env)?;
+    let (proposal_nft, _change) = add_scheduled_proposal(dao, PROPOSAL_ID, payment, Some(voting_start_epoch), Some(voting_start_epoch + 1), None, env)?;
+    add_option(dao, OPTION_ID, 1, "yes", env)?;
+
+    Ok((proposal_nft, voting_start_epoch))
+}
+
+fn add_option(dao: &mut TestDao, proposal_option_id: &str, rank: u32, option: &str, env: &mut TestEnvironment) -> Result<(), RuntimeError> {
+    dao.dao_hoard.proposal_add_option(
+        dao.admin_badge.create_proof_of_all(env)?,
+        DAO_ID.to_string(),
+        PROPOSAL_ID.to_string(),
+        proposal_option_id.to_string(),
+        rank,
+        option.to_string(),
+        HashMap::new(),
+        env,
+    )
+}
+
+// votes for OPTION_ID
+fn cast_vote(dao_hoard: &mut DaoHoard, proposal_vote_id: &str, voting_nfts: &Bucket, env: &mut TestEnvironment) -> Result<(), RuntimeError> {
+    dao_hoard.proposal_mint_nft_vote(
+        DAO_ID.to_string(),
+        PROPOSAL_ID.to_string(),
+        proposal_vote_id.to_string(),
+        OPTION_ID.to_string(),
+        FAUCET,
+        voting_nfts.create_proof_of_all(env)?,
+        "2024-02-01T12:00:00.000000Z".to_string(),
+        HashMap::new(),
+        env,
+    )
+}
+
+#[test]
+fn test_member_proposal_is_activated_by_seconds() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let mut dao = create_test_dao(&mut env)?;
+    LocalAuthZone::push(dao.admin_badge.create_proof_of_all(&mut env)?, &mut env)?;
+    dao.dao_hoard.dao_set_member_proposals(dao.admin_badge.create_proof_of_all(&mut env)?, DAO_ID.to_string(), Some(dec!("1")), true, 2, &mut env)?;
+    let proposer_nft = dao.governance_nfts.take(dec!("1"), &mut env)?;
+    let voting_start_epoch = env.get_current_epoch().number() + 1;
+    let payment = BucketFactory::create_fungible_bucket(dao.token_address, dec!("15"), CreationStrategy::Mock, &mut env)?;
+    dao.dao_hoard.dao_add_member_proposal(
+        proposer_nft.create_proof_of_all(&mut env)?,
+        payment,
+        DAO_ID.to_string(),
+        PROPOSAL_ID.to_string(),
+        "proposal title".to_string(),
+        "proposal abstract".to_string(),
+        "proposal specification".to_string(),
+        "2024-02-01T00:00:00.000000Z".to_string(),
+        Some(voting_start_epoch),
+        "2024-02-02T00:00:00.000000Z".to_string(),
+        Some(voting_start_epoch + 1),
+        None,
+        "2024-01-26T22:16:32.256163Z".to_string(),
+        "https://info.url".to_string(),
+        None,
+        HashMap::new(),
+        None,
+        &mut env,
+    )?;
+    let pending_proposal = dao.dao_hoard.proposal_get(DAO_ID.to_string(), PROPOSAL_ID.to_string(), &mut env)?;
+
+    // Act
+    dao.dao_hoard.proposal_second(dao.governance_nfts.create_proof_of_all(&mut env)?, DAO_ID.to_string(), PROPOSAL_ID.to_string(), &mut env)?;
+    let seconded_proposal = dao.dao_hoard.proposal_get(DAO_ID.to_string(), PROPOSAL_ID.to_string(), &mut env)?;
+
+    // Assert
+    assert_eq!(pending_proposal.status, ProposalStatus::PendingSponsorship);
+    assert_eq!(seconded_proposal.status, ProposalStatus::Active);
+
+    Ok(())
+}
+
+#[test]
+fn test_admin_badge_is_not_revoked_by_a_vote_below_quorum() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let mut dao = create_test_dao(&mut env)?;
+    LocalAuthZone::push(dao.owner_badge.create_proof_of_all(&mut env)?, &mut env)?;
+    LocalAuthZone::push(dao.admin_badge.create_proof_of_all(&mut env)?, &mut env)?;
+    dao.dao_hoard.dao_set_proposal_deposit(dao.admin_badge.create_proof_of_all(&mut env)?, DAO_ID.to_string(), dec!("0"), dec!("2"), DepositSlashTarget::DaoTreasury, &mut env)?;
+    dao.dao_hoard.dao_mint_admin_badge_by_owner(DAO_ID.to_string(), DaoAdminRole::Admin, None, &mut env)?;
+    let revoked_badge_id = dao.dao_hoard.dao_get_admins(DAO_ID.to_string(), &mut env)?
+        .into_iter()
+        .find(|admin| admin.role == DaoAdminRole::Admin)
+        .unwrap()
+        .badge_id;
+
+    let voting_start_epoch = env.get_current_epoch().number() + 1;
+    let payment = BucketFactory::create_fungible_bucket(dao.token_address, dec!("15"), CreationStrategy::Mock, &mut env)?;
+    dao.dao_hoard.dao_add_proposal(
+        dao.admin_badge.create_proof_of_all(&mut env)?,
+        payment,
+        DAO_ID.to_string(),
+        PROPOSAL_ID.to_string(),
+        "revoke admin".to_string(),
+        "proposal abstract".to_string(),
+        "proposal specification".to_string(),
+        "2024-02-01T00:00:00.000000Z".to_string(),
+        Some(voting_start_epoch),
+        "2024-02-02T00:00:00.000000Z".to_string(),
+        Some(voting_start_epoch + 1),
+        None,
+        "2024-01-26T22:16:32.256163Z".to_string(),
+        "https://info.url".to_string(),
+        None,
+        HashMap::from([("revoke_admin_badge".to_string(), revoked_badge_id.to_string())]),
+        None,
+        &mut env,
+    )?;
+    add_option(&mut dao, OPTION_ID, 1, "revoke", &mut env)?;
+    add_option(&mut dao, "8b3c4d5e-2f3a-4b6c-9d0e-1f2a3b4c5d6e", 2, "keep", &mut env)?;
+    env.set_current_epoch(Epoch::of(voting_start_epoch));
+    let single_nft = dao.governance_nfts.take(dec!("1"), &mut env)?;
+    cast_vote(&mut dao.dao_hoard, FIRST_VOTE_ID, &single_nft, &mut env)?;
+    env.set_current_epoch(Epoch::of(voting_start_epoch + 2));
+
+    // Act
+    let holder_vault = InternalAddress::new_or_panic([EntityType::InternalNonFungibleVault as u8; NodeId::LENGTH]);
+    let result = dao.dao_hoard.dao_revoke_admin_badge_by_vote(DAO_ID.to_string(), PROPOSAL_ID.to_string(), holder_vault, &mut env);
+
+    // Assert
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_renewed_admin_badge_is_accepted_until_its_new_expiry() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let mut dao = create_test_dao(&mut env)?;
+    let current_epoch = env.get_current_epoch().number();
+    LocalAuthZone::push(dao.owner_badge.create_proof_of_all(&mut env)?, &mut env)?;
+    LocalAuthZone::push(dao.admin_badge.create_proof_of_all(&mut env)?, &mut env)?;
+    let season_badge = dao.dao_hoard.dao_mint_admin_badge_by_owner(DAO_ID.to_string(), DaoAdminRole::Admin, Some(current_epoch + 2), &mut env)?;
+    let season_badge_id = dao.dao_hoard.dao_get_admins(DAO_ID.to_string(), &mut env)?
+        .into_iter()
+        .find(|admin| admin.role == DaoAdminRole::Admin)
+        .unwrap()
+        .badge_id;
+    dao.dao_hoard.dao_renew_admin_badge(dao.admin_badge.create_proof_of_all(&mut env)?, DAO_ID.to_string(), season_badge_id, Some(current_epoch + 10), &mut env)?;
+
+    // Act
+    env.set_current_epoch(Epoch::of(current_epoch + 5));
+    let patch = DaoPatch { name: Some("renamed dao".to_string()), ..Default::default() };
+    dao.dao_hoard.dao_patch(season_badge.create_proof_of_all(&mut env)?, DAO_ID.to_string(), patch, &mut env)?;
+    let renamed_dao = dao.dao_hoard.dao_get(DAO_ID.to_string(), &mut env)?;
+    env.set_current_epoch(Epoch::of(current_epoch + 11));
+    let late_patch = DaoPatch { name: Some("late dao".to_string()), ..Default::default() };
+    let late_result = dao.dao_hoard.dao_patch(season_badge.create_proof_of_all(&mut env)?, DAO_ID.to_string(), late_patch, &mut env);
+
+    // Assert
+    assert_eq!(renamed_dao.name, "renamed dao");
+    assert!(late_result.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_admin_badges_are_capped_per_dao() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let mut dao = create_test_dao(&mut env)?;
+    LocalAuthZone::push(dao.owner_badge.create_proof_of_all(&mut env)?, &mut env)?;
+    dao.dao_hoard.dao_hoard_set_dao_max_admins(DAO_ID.to_string(), Some(2), &mut env)?;
+
+    // Act
+    dao.dao_hoard.dao_mint_admin_badge_by_owner(DAO_ID.to_string(), DaoAdminRole::Moderator, None, &mut env)?;
+    let admins = dao.dao_hoard.dao_get_admins(DAO_ID.to_string(), &mut env)?;
+    let over_cap = dao.dao_hoard.dao_mint_admin_badge_by_owner(DAO_ID.to_string(), DaoAdminRole::Moderator, None, &mut env);
+
+    // Assert
+    assert_eq!(admins.len(), 2);
+    assert!(over_cap.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_proposal_price_follows_fee_resources_and_dao_overrides() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let mut dao = create_test_dao(&mut env)?;
+    let fee_tokens = ResourceBuilder::new_fungible(OwnerRole::None).mint_initial_supply(1, &mut env)?;
+    let fee_address = fee_tokens.resource_address(&mut env)?;
+    LocalAuthZone::push(dao.owner_badge.create_proof_of_all(&mut env)?, &mut env)?;
+    dao.dao_hoard.dao_hoard_set_fee_resource(fee_address, Some(dec!("30")), &mut env)?;
+
+    // Act
+    let regular_crumbs_price = dao.dao_hoard.dao_get_proposal_price(DAO_ID.to_string(), dao.token_address, &mut env)?;
+    let regular_fee_resource_price = dao.dao_hoard.dao_get_proposal_price(DAO_ID.to_string(), fee_address, &mut env)?;
+    dao.dao_hoard.dao_hoard_set_dao_proposal_fee(DAO_ID.to_string(), Some(ProposalFeeOverride::Price(dec!("5"))), &mut env)?;
+    let overridden_crumbs_price = dao.dao_hoard.dao_get_proposal_price(DAO_ID.to_string(), dao.token_address, &mut env)?;
+    let overridden_fee_resource_price = dao.dao_hoard.dao_get_proposal_price(DAO_ID.to_string(), fee_address, &mut env)?;
+
+    // Assert
+    assert_eq!(regular_crumbs_price, dec!("15"));
+    assert_eq!(regular_fee_resource_price, dec!("30"));
+    assert_eq!(overridden_crumbs_price, dec!("5"));
+    assert_eq!(overridden_fee_resource_price, dec!("10"));
+
+    Ok(())
+}
+
+#[test]
+fn test_additional_data_changes_until_voting_starts() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let mut dao = create_test_dao(&mut env)?;
+    LocalAuthZone::push(dao.admin_badge.create_proof_of_all(&mut env)?, &mut env)?;
+    let voting_start_epoch = env.get_current_epoch().number() + 1;
+    let payment = BucketFactory::create_fungible_bucket(dao.token_address, dec!("15"), CreationStrategy::Mock, &mut env)?;
+    add_scheduled_proposal(&mut dao, PROPOSAL_ID, payment, Some(voting_start_epoch), Some(voting_start_epoch + 1), None, &mut env)?;
+
+    // Act
+    dao.dao_hoard.dao_update_additional_data(
+        dao.admin_badge.create_proof_of_all(&mut env)?,
+        DAO_ID.to_string(),
+        vec![
+            AdditionalDataChange::Set { key: "website".to_string(), value: "https://dao.url".to_string() },
+            AdditionalDataChange::SetVec { key: "links".to_string(), values: vec!["https://first.url".to_string()] },
+            AdditionalDataChange::AppendVec { key: "links".to_string(), values: vec!["https://second.url".to_string()] },
+        ],
+        &mut env,
+    )?;
+    dao.dao_hoard.proposal_update_additional_data(
+        dao.admin_badge.create_proof_of_all(&mut env)?,
+        DAO_ID.to_string(),
+        PROPOSAL_ID.to_string(),
+        vec![AdditionalDataChange::SetVec { key: "links".to_string(), values: vec!["https://forum.url".to_string()] }],
+        &mut env,
+    )?;
+    let updated_dao = dao.dao_hoard.dao_get(DAO_ID.to_string(), &mut env)?;
+    let updated_proposal = dao.dao_hoard.proposal_get(DAO_ID.to_string(), PROPOSAL_ID.to_string(), &mut env)?;
+    env.set_current_epoch(Epoch::of(voting_start_epoch));
+    let late_change = dao.dao_hoard.proposal_update_additional_data(
+        dao.admin_badge.create_proof_of_all(&mut env)?,
+        DAO_ID.to_string(),
+        PROPOSAL_ID.to_string(),
+        vec![AdditionalDataChange::RemoveVec { key: "links".to_string() }],
+        &mut env,
+    );
+
+    // Assert
+    assert_eq!(updated_dao.additional_data["website"], "https://dao.url");
+    assert_eq!(updated_dao.additional_data_vec["links"], vec!["https://first.url".to_string(), "https://second.url".to_string()]);
+    assert_eq!(updated_proposal.additional_data_vec["links"], vec!["https://forum.url".to_string()]);
+    assert!(late_change.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_patch_writes_only_changes_and_raises_the_revision() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let mut dao = create_test_dao(&mut env)?;
+    LocalAuthZone::push(dao.admin_badge.create_proof_of_all(&mut env)?, &mut env)?;
+    let patch = DaoPatch {
+        key_image_url: Some("https://new-logo.url".to_string()),
+        about: Some("new about".to_string()),
+        ..Default::default()
+    };
+
+    // Act
+    dao.dao_hoard.dao_patch(dao.admin_badge.create_proof_of_all(&mut env)?, DAO_ID.to_string(), patch.clone(), &mut env)?;
+    let patched_dao = dao.dao_hoard.dao_get(DAO_ID.to_string(), &mut env)?;
+    dao.dao_hoard.dao_patch(dao.admin_badge.create_proof_of_all(&mut env)?, DAO_ID.to_string(), patch, &mut env)?;
+    let repatched_dao = dao.dao_hoard.dao_get(DAO_ID.to_string(), &mut env)?;
+
+    // Assert
+    assert_eq!(patched_dao.name, "dao name");
+    assert_eq!(patched_dao.key_image_url, Url::of("https://new-logo.url"));
+    assert_eq!(patched_dao.about, "new about");
+    assert_eq!(patched_dao.description, "new about");
+    assert_eq!(patched_dao.revision, 1);
+    assert_eq!(repatched_dao.revision, 1);
+
+    Ok(())
+}
+
+#[test]
+fn test_rename_and_logo_change_are_propagated_to_admin_badges() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let mut dao = create_test_dao(&mut env)?;
+    LocalAuthZone::push(dao.admin_badge.create_proof_of_all(&mut env)?, &mut env)?;
+    let patch = DaoPatch {
+        name: Some("renamed dao".to_string()),
+        key_image_url: Some("https://new-logo.url".to_string()),
+        ..Default::default()
+    };
+
+    // Act
+    dao.dao_hoard.dao_patch(dao.admin_badge.create_proof_of_all(&mut env)?, DAO_ID.to_string(), patch, &mut env)?;
+
+    // Assert
+    let badge_id = dao.dao_hoard.dao_get_admins(DAO_ID.to_string(), &mut env)?.remove(0).badge_id;
+    let admin_badge_address = dao.admin_badge.resource_address(&mut env)?;
+    let admin_badge: AdminBadgeData = env.call_method_typed(
+        *admin_badge_address.as_node_id(),
+        NON_FUNGIBLE_RESOURCE_MANAGER_GET_NON_FUNGIBLE_IDENT,
+        &NonFungibleResourceManagerGetNonFungibleInput { id: badge_id },
+    )?;
+    assert_eq!(admin_badge.dao_id, DAO_ID);
+    assert_eq!(admin_badge.name, "renamed dao Admin Badge");
+    assert_eq!(admin_badge.key_image_url, Url::of("https://new-logo.url"));
+
+    Ok(())
+}
+
+#[test]
+fn test_proposal_metadata_is_updated_until_voting_starts() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let mut dao = create_test_dao(&mut env)?;
+    LocalAuthZone::push(dao.admin_badge.create_proof_of_all(&mut env)?, &mut env)?;
+    let voting_start_epoch = env.get_current_epoch().number() + 1;
+    let payment = BucketFactory::create_fungible_bucket(dao.token_address, dec!("15"), CreationStrategy::Mock, &mut env)?;
+    add_scheduled_proposal(&mut dao, PROPOSAL_ID, payment, Some(voting_start_epoch), Some(voting_start_epoch + 1), None, &mut env)?;
+    let created_proposal = dao.dao_hoard.proposal_get(DAO_ID.to_string(), PROPOSAL_ID.to_string(), &mut env)?;
+
+    // Act
+    dao.dao_hoard.proposal_update(
+        dao.admin_badge.create_proof_of_all(&mut env)?,
+        DAO_ID.to_string(),
+        PROPOSAL_ID.to_string(),
+        Some("https://new-info.url".to_string()),
+        Some("https://proposal-image.url".to_string()),
+        None,
+        &mut env,
+    )?;
+    let updated_proposal = dao.dao_hoard.proposal_get(DAO_ID.to_string(), PROPOSAL_ID.to_string(), &mut env)?;
+    env.set_current_epoch(Epoch::of(voting_start_epoch));
+    let late_update = dao.dao_hoard.proposal_update(
+        dao.admin_badge.create_proof_of_all(&mut env)?,
+        DAO_ID.to_string(),
+        PROPOSAL_ID.to_string(),
+        Some("https://late-info.url".to_string()),
+        None,
+        None,
+        &mut env,
+    );
+
+    // Assert
+    assert_eq!(created_proposal.key_image_url, Url::of("https://logo.url"));
+    assert_eq!(updated_proposal.info_url, Url::of("https://new-info.url"));
+    assert_eq!(updated_proposal.key_image_url, Url::of("https://proposal-image.url"));
+    assert!(late_update.is_err());
 
     Ok(())
 }